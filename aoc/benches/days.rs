@@ -0,0 +1,76 @@
+//! Benchmarks of `parse`, `part1` and `part2` of every day, on the real input
+//! and on a larger input from its generator.
+//!
+//! Save a baseline before a change, then compare against it afterwards:
+//!
+//! ```text
+//! cargo bench -p aoc --bench days -- --save-baseline before
+//! cargo bench -p aoc --bench days -- --baseline before
+//! ```
+//!
+//! A single day or phase can be selected with a filter, e.g. `day05/generated`.
+
+use std::{fs::read_to_string, hint::black_box};
+
+use common::{Generator, Rng, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const SEED: u64 = 2021;
+
+/// Benchmarks `parse` and `part1` of a day on `input`, and `part2` too when
+/// `part2` is set.
+fn bench<S: Solution>(c: &mut Criterion, name: &str, input: &str, part2: bool) {
+    let mut group = c.benchmark_group(format!("day{:02}/{}", S::DAY, name));
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+
+    let parsed = S::parse(input).expect("Cannot parse benchmark input");
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    if part2 {
+        group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    }
+
+    group.finish();
+}
+
+/// Benchmarks the real input of a day, then one generated with `size`.
+fn bench_day<S: Generator>(c: &mut Criterion, real: &str, size: usize) {
+    bench::<S>(c, "real", real, true);
+    bench::<S>(
+        c,
+        "generated",
+        &S::generate(&mut Rng::new(SEED), size),
+        true,
+    );
+}
+
+fn days(c: &mut Criterion) {
+    let real = |day: &str| {
+        let path = format!("{}/../{}/input.txt", env!("CARGO_MANIFEST_DIR"), day);
+        read_to_string(&path).unwrap_or_else(|e| panic!("Cannot read {}: {}", path, e))
+    };
+
+    bench_day::<day01::Day01>(c, &real("day01"), 20_000);
+    bench_day::<day02::Day02>(c, &real("day02"), 10_000);
+    bench_day::<day03::Day03>(c, &real("day03"), 10_000);
+    bench_day::<day04::Day04>(c, &real("day04"), 1_000);
+    bench_day::<day05::Day05>(c, &real("day05"), 5_000);
+    bench_day::<day06::Day06>(c, &real("day06"), 3_000);
+    bench_day::<day07::Day07>(c, &real("day07"), 10_000);
+    bench_day::<day08::Day08>(c, &real("day08"), 2_000);
+    bench_day::<day09::Day09>(c, &real("day09"), 300);
+    bench_day::<day10::Day10>(c, &real("day10"), 1_000);
+    // Random octopuses may never all flash at once, so part 2 is only
+    // benchmarked on the real input. Part 1 always stops after 100 steps.
+    bench::<day11::Day11>(c, "real", &real("day11"), true);
+    bench::<day11::Day11>(
+        c,
+        "generated",
+        &day11::Day11::generate(&mut Rng::new(SEED), 100),
+        false,
+    );
+    bench_day::<day12::Day12>(c, &real("day12"), 12);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,87 @@
+//! Fingerprints the sources of every day crate, with the sources of the
+//! crates it depends on, so that cached answers of a day only go stale when
+//! the code solving it changes.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+#[path = "src/hash.rs"]
+mod hash;
+
+fn main() {
+    let root = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap())
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    // New days are registered in the manifest of the runner.
+    println!("cargo:rerun-if-changed=Cargo.toml");
+
+    let mut days = fs::read_dir(&root)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let day = name.strip_prefix("day")?.parse::<u8>().ok()?;
+            Some((day, name))
+        })
+        .collect::<Vec<_>>();
+    days.sort();
+
+    let fingerprints = days
+        .iter()
+        .map(|(day, name)| format!("    ({}, {:#018x}),\n", day, fingerprint(&root, name)))
+        .collect::<String>();
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("fingerprints.rs");
+    fs::write(
+        out,
+        format!(
+            "/// Fingerprint of the sources of every day.\npub const FINGERPRINTS: &[(u8, u64)] = &[\n{}];\n",
+            fingerprints
+        ),
+    )
+    .unwrap();
+}
+
+/// Hash of the manifest and sources of crate `name`, and of the sources of
+/// the workspace crates it depends on.
+fn fingerprint(root: &Path, name: &str) -> u64 {
+    let manifest = root.join(name).join("Cargo.toml");
+    let text = fs::read_to_string(&manifest).unwrap_or_default();
+    println!("cargo:rerun-if-changed={}", manifest.display());
+
+    let mut crates = text
+        .lines()
+        .filter_map(|line| line.split_once("path = \"../")?.1.split_once('"'))
+        .map(|(dependency, _)| dependency.to_string())
+        .collect::<Vec<_>>();
+    crates.push(name.to_string());
+    crates.sort();
+
+    let mut files = Vec::new();
+    for krate in &crates {
+        let src = root.join(krate).join("src");
+        println!("cargo:rerun-if-changed={}", src.display());
+        sources(&src, &mut files);
+    }
+    files.sort();
+
+    let mut bytes = text.into_bytes();
+    for file in files {
+        bytes.extend(file.strip_prefix(root).unwrap().to_string_lossy().bytes());
+        bytes.extend(fs::read(&file).unwrap_or_default());
+    }
+    hash::fnv1a(&bytes)
+}
+
+fn sources(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            sources(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
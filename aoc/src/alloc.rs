@@ -0,0 +1,102 @@
+//! Global allocator counting what each thread allocates, so that a part can
+//! be profiled while others run on other threads.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    ops::Sub,
+};
+
+/// Allocations made so far, or during some phase when subtracted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Allocations {
+    /// Calls to allocate or grow memory.
+    pub count: u64,
+    /// Bytes requested by those calls. Growing counts the extra bytes only.
+    pub bytes: u64,
+}
+
+impl Sub for Allocations {
+    type Output = Allocations;
+
+    fn sub(self, other: Allocations) -> Allocations {
+        Allocations {
+            count: self.count - other.count,
+            bytes: self.bytes - other.bytes,
+        }
+    }
+}
+
+thread_local! {
+    static ALLOCATIONS: Cell<Allocations> = const {
+        Cell::new(Allocations { count: 0, bytes: 0 })
+    };
+}
+
+/// Allocations of the current thread since it started.
+pub fn current() -> Allocations {
+    ALLOCATIONS.with(Cell::get)
+}
+
+fn record(bytes: usize) {
+    // Fails once the thread is being destroyed, when counts do not matter.
+    let _ = ALLOCATIONS.try_with(|a| {
+        let Allocations {
+            count,
+            bytes: total,
+        } = a.get();
+        a.set(Allocations {
+            count: count + 1,
+            bytes: total + bytes as u64,
+        })
+    });
+}
+
+/// [`System`] allocator counting allocations in [`current`].
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if layout.size() < new_size {
+            record(new_size - layout.size());
+        }
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::hint::black_box;
+
+    use super::*;
+
+    #[test]
+    fn current_test() {
+        let before = current();
+        let mut v = black_box(Vec::<u64>::with_capacity(4));
+        v.extend(0..8);
+        drop(black_box(v));
+
+        assert_eq!(
+            current() - before,
+            Allocations {
+                count: 2,
+                bytes: 64
+            }
+        );
+    }
+}
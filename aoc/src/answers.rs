@@ -0,0 +1,87 @@
+use std::{fs, io, path::PathBuf};
+
+/// Known answers of a day on its real input, read from `dayNN/answers.toml`:
+///
+/// ```toml
+/// part1 = 1228
+/// part2 = "1257"
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn path(day: u8) -> PathBuf {
+        format!("day{:02}/answers.toml", day).into()
+    }
+
+    /// Reads the answers of `day`, none being known when the file is missing.
+    pub fn read(day: u8) -> Result<Answers, String> {
+        let path = Answers::path(day);
+
+        match fs::read_to_string(&path) {
+            Ok(s) => Answers::parse(&s).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("Cannot read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn parse(s: &str) -> Result<Answers, String> {
+        let mut answers = Answers::default();
+
+        for (i, l) in s.lines().enumerate() {
+            let l = l.trim();
+            if l.is_empty() || l.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = l
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected key = value", i + 1))?;
+
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+            let value = Some(value.to_string()).filter(|v| !v.is_empty());
+
+            match key.trim() {
+                "part1" => answers.part1 = value,
+                "part2" => answers.part2 = value,
+                k => return Err(format!("line {}: unknown key {}", i + 1, k)),
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_test() {
+        assert_eq!(
+            Answers::parse("# Day 01\npart1 = 1228\npart2 = \"1257\"\n"),
+            Ok(Answers {
+                part1: Some("1228".to_string()),
+                part2: Some("1257".to_string()),
+            })
+        );
+        assert_eq!(Answers::parse("part1 = \"\"\n"), Ok(Answers::default()));
+        assert!(Answers::parse("part3 = 1").is_err());
+        assert!(Answers::parse("part1 1").is_err());
+    }
+}
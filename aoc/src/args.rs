@@ -0,0 +1,514 @@
+use common::InputSource;
+
+use crate::{days::DAYS, viz};
+
+pub const USAGE: &str = "Usage:
+    aoc run <day|all> [--part <1|2>] [--input <path|->] [--inline <text>]
+        [--output <text|json>] [--jobs <n>] [--stream] [--no-cache]
+    aoc profile <day|all> [same options as run]
+    aoc cache clear
+    aoc list
+    aoc info <day>
+    aoc verify [day|all]
+    aoc check <day|all> [--input <path|->] [--inline <text>]
+    aoc gen <day> [--size <n>] [--seed <n>]
+    aoc new-day <day>
+    aoc viz <day> [--steps <n>] [--fps <n>] [--input <path|->] [--inline <text>]";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(Run),
+    /// Runs like `run` and reports the time and allocations of each phase.
+    Profile(Run),
+    ClearCache,
+    List,
+    Info(u8),
+    Verify(Selection),
+    Check(Check),
+    Gen(Gen),
+    NewDay(u8),
+    Viz(Viz),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Viz {
+    pub day: u8,
+    /// Frames shown at most, all of them when not set.
+    pub steps: Option<usize>,
+    pub fps: u32,
+    pub input: Option<InputSource>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Check {
+    pub selection: Selection,
+    pub input: Option<InputSource>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Gen {
+    pub day: u8,
+    pub size: usize,
+    pub seed: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Run {
+    pub selection: Selection,
+    pub part: Option<u8>,
+    pub input: Option<InputSource>,
+    pub output: Output,
+    /// Parts run at the same time, as many as the cores when not set.
+    pub jobs: Option<usize>,
+    /// Whether parts read their input line by line, for inputs larger than
+    /// memory.
+    pub stream: bool,
+    /// Whether to solve again the parts whose answer is cached.
+    pub no_cache: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Output {
+    Text,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+impl Selection {
+    pub fn contains(&self, day: u8) -> bool {
+        match self {
+            Selection::All => true,
+            Selection::Day(d) => *d == day,
+        }
+    }
+}
+
+pub fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => parse_run(args).map(Command::Run),
+        Some("profile") => parse_run(args).map(Command::Profile),
+        Some("list") => match args.next() {
+            Some(a) => Err(format!("Unexpected argument {}", a)),
+            None => Ok(Command::List),
+        },
+        Some("info") => {
+            let selection = parse_selection(&args.next().ok_or("Missing day")?)?;
+            match (selection, args.next()) {
+                (Selection::Day(day), None) => Ok(Command::Info(day)),
+                (Selection::All, None) => Err("Use list to describe all days".to_string()),
+                (_, Some(a)) => Err(format!("Unexpected argument {}", a)),
+            }
+        }
+        Some("cache") => match (args.next().as_deref(), args.next()) {
+            (Some("clear"), None) => Ok(Command::ClearCache),
+            (Some("clear"), Some(a)) => Err(format!("Unexpected argument {}", a)),
+            (Some(a), _) => Err(format!("Unknown cache command {}", a)),
+            (None, _) => Err("Missing cache command".to_string()),
+        },
+        Some("verify") => {
+            let selection = match args.next() {
+                Some(d) => parse_selection(&d)?,
+                None => Selection::All,
+            };
+            match args.next() {
+                Some(a) => Err(format!("Unexpected argument {}", a)),
+                None => Ok(Command::Verify(selection)),
+            }
+        }
+        Some("check") => parse_check(args).map(Command::Check),
+        Some("gen") => parse_gen(args).map(Command::Gen),
+        Some("new-day") => {
+            let day = args.next().ok_or("Missing day")?;
+            match (day.parse(), args.next()) {
+                (Ok(day @ 1..=25), None) => Ok(Command::NewDay(day)),
+                (_, Some(a)) => Err(format!("Unexpected argument {}", a)),
+                (_, None) => Err(format!("Unknown day {}", day)),
+            }
+        }
+        Some("viz") => parse_viz(args).map(Command::Viz),
+        Some(c) => Err(format!("Unknown command {}", c)),
+        None => Err("Missing command".to_string()),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Run, String> {
+    let selection = parse_selection(&args.next().ok_or("Missing day")?)?;
+
+    let mut part = None;
+    let mut input = None;
+    let mut output = Output::Text;
+    let mut jobs = None;
+    let mut stream = false;
+    let mut no_cache = false;
+
+    while let Some(flag) = args.next() {
+        // Flags without a value.
+        match flag.as_str() {
+            "--stream" => {
+                stream = true;
+                continue;
+            }
+            "--no-cache" => {
+                no_cache = true;
+                continue;
+            }
+            _ => {}
+        }
+
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+
+        match flag.as_str() {
+            "--part" => match value.as_str() {
+                "1" => part = Some(1),
+                "2" => part = Some(2),
+                p => return Err(format!("Unknown part {}", p)),
+            },
+            "--output" => match value.as_str() {
+                "text" => output = Output::Text,
+                "json" => output = Output::Json,
+                o => return Err(format!("Unknown output {}", o)),
+            },
+            "--jobs" => match value.parse() {
+                Ok(n) if 0 < n => jobs = Some(n),
+                _ => return Err(format!("Invalid jobs {}", value)),
+            },
+            f => {
+                input = Some(
+                    InputSource::from_flag(f, value)
+                        .ok_or_else(|| format!("Unknown flag {}", f))?,
+                )
+            }
+        }
+    }
+
+    if input.is_some() && selection == Selection::All {
+        return Err("--input and --inline cannot be used with all days".to_string());
+    }
+    // Each part reads its input again, which standard input only allows once.
+    if stream && input == Some(InputSource::Stdin) && part.is_none() {
+        return Err("--stream on standard input needs --part".to_string());
+    }
+
+    Ok(Run {
+        selection,
+        part,
+        input,
+        output,
+        jobs,
+        stream,
+        no_cache,
+    })
+}
+
+fn parse_check(mut args: impl Iterator<Item = String>) -> Result<Check, String> {
+    let selection = parse_selection(&args.next().ok_or("Missing day")?)?;
+
+    let mut input = None;
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+        input = Some(
+            InputSource::from_flag(&flag, value).ok_or_else(|| format!("Unknown flag {}", flag))?,
+        );
+    }
+
+    if input.is_some() && selection == Selection::All {
+        return Err("--input and --inline cannot be used with all days".to_string());
+    }
+
+    Ok(Check { selection, input })
+}
+
+fn parse_gen(mut args: impl Iterator<Item = String>) -> Result<Gen, String> {
+    let day = match parse_selection(&args.next().ok_or("Missing day")?)? {
+        Selection::Day(day) => day,
+        Selection::All => return Err("Cannot generate inputs of all days at once".to_string()),
+    };
+
+    let max_size = DAYS
+        .iter()
+        .find(|d| d.number == day)
+        .map_or(usize::MAX, |d| d.max_size);
+    let mut gen = Gen {
+        day,
+        size: max_size.min(100),
+        seed: 2021,
+    };
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+
+        match flag.as_str() {
+            "--size" => {
+                gen.size = value
+                    .parse()
+                    .map_err(|_| format!("Invalid size {}", value))?
+            }
+            "--seed" => {
+                gen.seed = value
+                    .parse()
+                    .map_err(|_| format!("Invalid seed {}", value))?
+            }
+            f => return Err(format!("Unknown flag {}", f)),
+        }
+    }
+
+    if max_size < gen.size {
+        return Err(format!(
+            "Day {:02} generates inputs of size at most {}",
+            day, max_size
+        ));
+    }
+
+    Ok(gen)
+}
+
+fn parse_viz(mut args: impl Iterator<Item = String>) -> Result<Viz, String> {
+    let day = args.next().ok_or("Missing day")?;
+    let day = match day.parse() {
+        Ok(d) if viz::DAYS.contains(&d) => d,
+        _ => return Err(format!("No visualization for day {}", day)),
+    };
+
+    let mut viz = Viz {
+        day,
+        steps: None,
+        fps: 10,
+        input: None,
+    };
+
+    while let Some(flag) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {}", flag))?;
+
+        match flag.as_str() {
+            "--steps" => {
+                viz.steps = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("Invalid steps {}", value))?,
+                )
+            }
+            "--fps" => match value.parse() {
+                Ok(n) if 0 < n => viz.fps = n,
+                _ => return Err(format!("Invalid fps {}", value)),
+            },
+            f => {
+                viz.input = Some(
+                    InputSource::from_flag(f, value)
+                        .ok_or_else(|| format!("Unknown flag {}", f))?,
+                )
+            }
+        }
+    }
+
+    Ok(viz)
+}
+
+fn parse_selection(s: &str) -> Result<Selection, String> {
+    match s {
+        "all" => Ok(Selection::All),
+        d => match d.parse() {
+            Ok(d) if DAYS.iter().any(|day| day.number == d) => Ok(Selection::Day(d)),
+            _ => Err(format!("Unknown day {}", d)),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(s: &str) -> impl Iterator<Item = String> + '_ {
+        s.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn parse_args_test() {
+        assert_eq!(
+            parse_args(args("run 7 --part 2 --input path --output json")),
+            Ok(Command::Run(Run {
+                selection: Selection::Day(7),
+                part: Some(2),
+                input: Some(InputSource::File("path".into())),
+                output: Output::Json,
+                jobs: None,
+                stream: false,
+                no_cache: false,
+            }))
+        );
+
+        assert_eq!(
+            parse_args(args("run 6 --input -")),
+            Ok(Command::Run(Run {
+                selection: Selection::Day(6),
+                part: None,
+                input: Some(InputSource::Stdin),
+                output: Output::Text,
+                jobs: None,
+                stream: false,
+                no_cache: false,
+            }))
+        );
+
+        assert_eq!(
+            parse_args(args("run all --no-cache --jobs 4")),
+            Ok(Command::Run(Run {
+                selection: Selection::All,
+                part: None,
+                input: None,
+                output: Output::Text,
+                jobs: Some(4),
+                stream: false,
+                no_cache: true,
+            }))
+        );
+
+        assert_eq!(
+            parse_args(args("profile 8 --stream --part 1 --jobs 1")),
+            Ok(Command::Profile(Run {
+                selection: Selection::Day(8),
+                part: Some(1),
+                input: None,
+                output: Output::Text,
+                jobs: Some(1),
+                stream: true,
+                no_cache: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_args_list_test() {
+        assert_eq!(parse_args(args("list")), Ok(Command::List));
+        assert_eq!(parse_args(args("info 5")), Ok(Command::Info(5)));
+        assert!(parse_args(args("list 5")).is_err());
+        assert!(parse_args(args("info all")).is_err());
+        assert!(parse_args(args("info 13")).is_err());
+    }
+
+    #[test]
+    fn parse_args_cache_test() {
+        assert_eq!(parse_args(args("cache clear")), Ok(Command::ClearCache));
+        assert!(parse_args(args("cache")).is_err());
+        assert!(parse_args(args("cache drop")).is_err());
+        assert!(parse_args(args("cache clear 1")).is_err());
+    }
+
+    #[test]
+    fn parse_args_verify_test() {
+        assert_eq!(
+            parse_args(args("verify")),
+            Ok(Command::Verify(Selection::All))
+        );
+        assert_eq!(
+            parse_args(args("verify 4")),
+            Ok(Command::Verify(Selection::Day(4)))
+        );
+    }
+
+    #[test]
+    fn parse_args_check_test() {
+        assert_eq!(
+            parse_args(args("check all")),
+            Ok(Command::Check(Check {
+                selection: Selection::All,
+                input: None,
+            }))
+        );
+        assert_eq!(
+            parse_args(args("check 4 --inline x")),
+            Ok(Command::Check(Check {
+                selection: Selection::Day(4),
+                input: Some(InputSource::Inline("x".to_string())),
+            }))
+        );
+        assert!(parse_args(args("check all --input -")).is_err());
+        assert!(parse_args(args("check 4 --part 1")).is_err());
+    }
+
+    #[test]
+    fn parse_args_gen_test() {
+        assert_eq!(
+            parse_args(args("gen 4 --size 1000 --seed 7")),
+            Ok(Command::Gen(Gen {
+                day: 4,
+                size: 1000,
+                seed: 7,
+            }))
+        );
+        assert_eq!(
+            parse_args(args("gen 12")),
+            Ok(Command::Gen(Gen {
+                day: 12,
+                size: 20,
+                seed: 2021,
+            }))
+        );
+        assert!(parse_args(args("gen 12 --size 20")).is_ok());
+        assert_eq!(
+            parse_args(args("gen 12 --size 21")),
+            Err("Day 12 generates inputs of size at most 20".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_args_new_day_test() {
+        assert_eq!(parse_args(args("new-day 13")), Ok(Command::NewDay(13)));
+        assert!(parse_args(args("new-day 26")).is_err());
+        assert!(parse_args(args("new-day")).is_err());
+        assert!(parse_args(args("new-day 13 14")).is_err());
+    }
+
+    #[test]
+    fn parse_args_viz_test() {
+        assert_eq!(
+            parse_args(args("viz 11 --steps 200 --fps 30")),
+            Ok(Command::Viz(Viz {
+                day: 11,
+                steps: Some(200),
+                fps: 30,
+                input: None,
+            }))
+        );
+        assert_eq!(
+            parse_args(args("viz 9 --input -")),
+            Ok(Command::Viz(Viz {
+                day: 9,
+                steps: None,
+                fps: 10,
+                input: Some(InputSource::Stdin),
+            }))
+        );
+        assert_eq!(
+            parse_args(args("viz 1")),
+            Err("No visualization for day 1".to_string())
+        );
+        assert!(parse_args(args("viz 5 --fps 0")).is_err());
+    }
+
+    #[test]
+    fn parse_args_error_test() {
+        assert!(parse_args(args("run 13")).is_err());
+        assert!(parse_args(args("run 1 --part 3")).is_err());
+        assert!(parse_args(args("run 1 --output xml")).is_err());
+        assert!(parse_args(args("run 1 --jobs 0")).is_err());
+        assert!(parse_args(args("run 1 --stream --input -")).is_err());
+
+        assert!(parse_args(args("run all --input path")).is_err());
+        assert!(parse_args(args("verify 4 5")).is_err());
+        assert!(parse_args(args("gen all")).is_err());
+        assert!(parse_args(args("gen 4 --size -1")).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+//! Answers saved on disk, so that parts are not solved again while neither
+//! their input nor the code of their day changes.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{alloc::Allocations, days::Answer};
+
+include!(concat!(env!("OUT_DIR"), "/fingerprints.rs"));
+
+/// Default directory of the cache, relative to the repository root.
+pub const DIR: &str = ".aoc-cache";
+
+pub struct Cache {
+    dir: PathBuf,
+    /// Hash of the sources of each day, which changes with any change to the
+    /// day or to the crates it depends on.
+    fingerprints: &'static [(u8, u64)],
+}
+
+impl Cache {
+    /// Cache in `dir` for the days this runner was built with.
+    pub fn open(dir: impl Into<PathBuf>) -> Cache {
+        Cache::with_fingerprints(dir, FINGERPRINTS)
+    }
+
+    fn with_fingerprints(dir: impl Into<PathBuf>, fingerprints: &'static [(u8, u64)]) -> Cache {
+        Cache {
+            dir: dir.into(),
+            fingerprints,
+        }
+    }
+
+    /// Answer saved for `part` of `day` on the input of hash `input`.
+    pub fn get(&self, day: u8, part: u8, input: u64) -> Option<Answer> {
+        let text = fs::read_to_string(self.path(day, part, input)?).ok()?;
+        let (type_name, value) = text.split_once('\n')?;
+
+        Some(Answer {
+            value: value.to_string(),
+            type_name: type_name.to_string(),
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+            parse_allocations: Allocations::default(),
+            solve_allocations: Allocations::default(),
+            cached: true,
+        })
+    }
+
+    /// Saves `answer`. The cache is only an optimization, so failing to
+    /// write it is not an error.
+    pub fn put(&self, day: u8, part: u8, input: u64, answer: &Answer) {
+        let Some(path) = self.path(day, part, input) else {
+            return;
+        };
+        let _ = fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(path, format!("{}\n{}", answer.type_name, answer.value)));
+    }
+
+    /// Path of the answer, None for a day without fingerprint.
+    fn path(&self, day: u8, part: u8, input: u64) -> Option<PathBuf> {
+        let &(_, fingerprint) = self.fingerprints.iter().find(|&&(d, _)| d == day)?;
+        Some(self.dir.join(format!(
+            "day{:02}-part{}-{:016x}-{:016x}",
+            day, part, input, fingerprint
+        )))
+    }
+}
+
+/// Removes every answer saved in `dir`, returning how many there were.
+pub fn clear(dir: &Path) -> io::Result<usize> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries.count(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+    fs::remove_dir_all(dir)?;
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cache_test() {
+        let dir = temp_dir("cache_test");
+        let cache = Cache::with_fingerprints(&dir, &[(1, 1)]);
+        let answer = Answer {
+            value: "1228".to_string(),
+            type_name: "usize".to_string(),
+            parse_time: Duration::from_millis(1),
+            solve_time: Duration::from_millis(2),
+            parse_allocations: Allocations::default(),
+            solve_allocations: Allocations::default(),
+            cached: false,
+        };
+
+        assert!(cache.get(1, 1, 42).is_none());
+        cache.put(1, 1, 42, &answer);
+
+        let cached = cache.get(1, 1, 42).unwrap();
+        assert_eq!(
+            (cached.value.as_str(), cached.type_name.as_str()),
+            ("1228", "usize")
+        );
+        assert!(cached.cached);
+
+        assert!(cache.get(1, 2, 42).is_none());
+        assert!(cache.get(1, 1, 43).is_none());
+        assert!(Cache::with_fingerprints(&dir, &[(1, 2)])
+            .get(1, 1, 42)
+            .is_none());
+        assert!(cache.get(2, 1, 42).is_none());
+
+        assert_eq!(clear(&dir).unwrap(), 1);
+        assert_eq!(clear(&dir).unwrap(), 0);
+    }
+
+    #[test]
+    fn cache_other_day_change_test() {
+        let dir = temp_dir("cache_other_day_change_test");
+        let before = Cache::with_fingerprints(&dir, &[(1, 1), (2, 1)]);
+        before.put(1, 1, 42, &answer("1228"));
+        before.put(2, 1, 42, &answer("1857958"));
+
+        let after = Cache::with_fingerprints(&dir, &[(1, 1), (2, 2)]);
+        assert_eq!(after.get(1, 1, 42).unwrap().value, "1228");
+        assert!(after.get(2, 1, 42).is_none());
+
+        clear(&dir).unwrap();
+    }
+
+    #[test]
+    fn fingerprints_test() {
+        let days = FINGERPRINTS.iter().map(|&(d, _)| d).collect::<Vec<_>>();
+        let registered = crate::days::DAYS
+            .iter()
+            .map(|d| d.number)
+            .collect::<Vec<_>>();
+        assert_eq!(days, registered);
+    }
+
+    fn temp_dir(test: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc-{}-{}", test, std::process::id()))
+    }
+
+    fn answer(value: &str) -> Answer {
+        Answer {
+            value: value.to_string(),
+            type_name: "usize".to_string(),
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+            parse_allocations: Allocations::default(),
+            solve_allocations: Allocations::default(),
+            cached: false,
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    any::type_name,
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use common::{Generator, Metadata, ParseError, PuzzleError, Rng, Solution, SolveError};
+
+use crate::alloc::{self, Allocations};
+
+pub struct Day {
+    pub number: u8,
+    pub metadata: Metadata,
+    /// Types of the answers of both parts.
+    pub answer_types: fn() -> [&'static str; 2],
+    pub part1: fn(&str) -> Result<Answer, PuzzleError>,
+    pub part2: fn(&str) -> Result<Answer, PuzzleError>,
+    pub check: fn(&str) -> Vec<ParseError>,
+    pub stream1: StreamPart,
+    pub stream2: StreamPart,
+    pub generate: fn(&mut Rng, usize) -> String,
+    /// Largest size of the generated inputs.
+    pub max_size: usize,
+}
+
+/// Part reading its input line by line, `None` when the day cannot.
+pub type StreamPart = fn(&mut dyn Iterator<Item = String>) -> Option<Result<Answer, PuzzleError>>;
+
+impl Day {
+    const fn new<S: Generator>() -> Day {
+        Day {
+            number: S::DAY,
+            metadata: S::METADATA,
+            answer_types: || [type_name::<S::Answer1>(), type_name::<S::Answer2>()],
+            part1: |input| answer::<S, _>(input, S::part1),
+            part2: |input| answer::<S, _>(input, S::part2),
+            check: S::check,
+            stream1: |lines| streamed_answer(lines, S::stream1),
+            stream2: |lines| streamed_answer(lines, S::stream2),
+            generate: S::generate,
+            max_size: S::MAX_SIZE,
+        }
+    }
+}
+
+/// Answer of one part, with how long it took to parse the input and to solve,
+/// and what each phase allocated.
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub value: String,
+    pub type_name: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    pub parse_allocations: Allocations,
+    pub solve_allocations: Allocations,
+    /// Whether the answer comes from the cache rather than from solving.
+    pub cached: bool,
+}
+
+fn answer<S: Solution, A: Display>(
+    input: &str,
+    solve: fn(&S::Input<'_>) -> Result<A, SolveError>,
+) -> Result<Answer, PuzzleError> {
+    let allocations = alloc::current();
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_time = start.elapsed();
+    let parse_allocations = alloc::current() - allocations;
+
+    let allocations = alloc::current();
+    let start = Instant::now();
+    let value = solve(&input)?;
+    let solve_time = start.elapsed();
+    let solve_allocations = alloc::current() - allocations;
+
+    Ok(Answer {
+        value: value.to_string(),
+        type_name: type_name::<A>().to_string(),
+        parse_time,
+        solve_time,
+        parse_allocations,
+        solve_allocations,
+        cached: false,
+    })
+}
+
+/// Answer of a streaming part, whose parsing happens while solving: all of
+/// its time and allocations count as solving.
+fn streamed_answer<A: Display>(
+    lines: &mut dyn Iterator<Item = String>,
+    stream: fn(&mut dyn Iterator<Item = String>) -> Option<Result<A, PuzzleError>>,
+) -> Option<Result<Answer, PuzzleError>> {
+    let allocations = alloc::current();
+    let start = Instant::now();
+    let value = stream(lines)?;
+    let solve_time = start.elapsed();
+    let solve_allocations = alloc::current() - allocations;
+
+    Some(value.map(|value| Answer {
+        value: value.to_string(),
+        type_name: type_name::<A>().to_string(),
+        parse_time: Duration::ZERO,
+        solve_time,
+        parse_allocations: Allocations::default(),
+        solve_allocations,
+        cached: false,
+    }))
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+];
@@ -0,0 +1,181 @@
+mod alloc;
+mod answers;
+mod args;
+mod cache;
+mod days;
+mod hash;
+mod pool;
+mod report;
+mod scaffold;
+mod verify;
+mod viz;
+
+use std::{env, path::Path, process, thread};
+
+use args::{parse_args, Command, Output, Run, USAGE};
+use cache::Cache;
+use common::{InputSource, Rng};
+use days::{Day, DAYS};
+use report::Report;
+
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
+
+fn main() {
+    let command = parse_args(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2)
+    });
+
+    let failed = match command {
+        Command::Run(run) => run_days(run, false),
+        Command::Profile(run) => run_days(run, true),
+        Command::List => {
+            for day in DAYS {
+                println!("{:02}  {}", day.number, day.metadata.title);
+            }
+            false
+        }
+        Command::Info(number) => {
+            let day = DAYS
+                .iter()
+                .find(|d| d.number == number)
+                .expect("Day was checked when parsing arguments");
+            print_info(day);
+            false
+        }
+        Command::ClearCache => match cache::clear(Path::new(cache::DIR)) {
+            Ok(n) => {
+                println!("Removed {} cached answers", n);
+                false
+            }
+            Err(e) => {
+                eprintln!("Cannot clear {}: {}", cache::DIR, e);
+                true
+            }
+        },
+        Command::Verify(selection) => {
+            !verify::verify(DAYS.iter().filter(|d| selection.contains(d.number)))
+        }
+        Command::Check(check) => {
+            let mut failed = false;
+            for day in DAYS.iter().filter(|d| check.selection.contains(d.number)) {
+                let source = check
+                    .input
+                    .clone()
+                    .unwrap_or_else(|| default_input(day.number));
+                let errors = match source.read() {
+                    Ok(input) => (day.check)(&input).iter().map(|e| e.to_string()).collect(),
+                    Err(e) => vec![e.to_string()],
+                };
+
+                if errors.is_empty() {
+                    println!("Day {:02}: ok", day.number);
+                } else {
+                    println!(
+                        "Day {:02}: {} problem{}",
+                        day.number,
+                        errors.len(),
+                        if errors.len() == 1 { "" } else { "s" }
+                    );
+
+                    errors.iter().for_each(|e| println!("  {}", e));
+                    failed = true;
+                }
+            }
+            failed
+        }
+        Command::Gen(gen) => {
+            let day = DAYS
+                .iter()
+                .find(|d| d.number == gen.day)
+                .expect("Day was checked when parsing arguments");
+            print!("{}", (day.generate)(&mut Rng::new(gen.seed), gen.size));
+            false
+        }
+        Command::NewDay(day) => match scaffold::new_day(Path::new("."), day) {
+            Ok(paths) => {
+                paths.iter().for_each(|p| println!("{}", p));
+                false
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                true
+            }
+        },
+        Command::Viz(v) => {
+            let source = v.input.unwrap_or_else(|| default_input(v.day));
+            let played = source
+                .read()
+                .map_err(|e| e.to_string())
+                .and_then(|input| viz::animation(v.day, &input))
+                .and_then(|mut animation| {
+                    let options = viz::Options {
+                        steps: v.steps,
+                        fps: v.fps,
+                    };
+                    viz::play(animation.as_mut(), &options).map_err(|e| e.to_string())
+                });
+            if let Err(e) = &played {
+                eprintln!("{}", e);
+            }
+            played.is_err()
+        }
+    };
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Runs the selected days and prints their reports, with the allocations of
+/// every phase when profiling. Returns whether any of them failed.
+fn run_days(run: Run, profile: bool) -> bool {
+    let days = DAYS
+        .iter()
+        .filter(|d| run.selection.contains(d.number))
+        .map(|day| {
+            let source = run
+                .input
+                .clone()
+                .unwrap_or_else(|| default_input(day.number));
+            (day, source)
+        })
+        .collect();
+    let jobs = run
+        .jobs
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()));
+    let reports = if run.stream {
+        Report::stream_all(days, run.part, jobs)
+    } else {
+        // Profiles measure solving, which cached answers skip.
+        let cache = (!run.no_cache && !profile).then(|| Cache::open(cache::DIR));
+        Report::run_all(days, run.part, jobs, cache.as_ref())
+    };
+
+    match run.output {
+        Output::Text if profile => report::print_profile(&reports),
+        Output::Text => report::print_text(&reports),
+        Output::Json => report::print_json(&reports, profile),
+    }
+
+    reports.iter().any(|r| r.error.is_some())
+}
+
+fn print_info(day: &Day) {
+    let metadata = &day.metadata;
+
+    println!("Day {}: {}", day.number, metadata.title);
+    println!("Input: {}", metadata.input);
+    for (part, answer_type) in (1..).zip((day.answer_types)()) {
+        println!("Part {}: answer of type {}", part, answer_type);
+    }
+    println!("\nExample:\n{}", metadata.example.trim_end());
+    for (part, answer) in (1..).zip(metadata.answers) {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+fn default_input(day: u8) -> InputSource {
+    InputSource::File(format!("day{:02}/input.txt", day).into())
+}
@@ -0,0 +1,59 @@
+use std::{
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+/// Applies `f` to every item on up to `jobs` threads, each thread taking the
+/// next item not started yet. Results come back in the order of `items`.
+pub fn map<I: Sync, T: Send>(jobs: usize, items: &[I], f: impl Fn(&I) -> T + Sync) -> Vec<T> {
+    let next = AtomicUsize::new(0);
+
+    let mut results = thread::scope(|s| {
+        let workers = (0..jobs.clamp(1, items.len().max(1)))
+            .map(|_| {
+                s.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        match items.get(i) {
+                            Some(item) => done.push((i, f(item))),
+                            None => return done,
+                        }
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect::<Vec<_>>()
+    });
+
+    results.sort_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{thread::sleep, time::Duration};
+
+    use super::*;
+
+    #[test]
+    fn map_test() {
+        let items = (0..50).collect::<Vec<u64>>();
+        // Early items take longer, so that they finish last.
+        let square = |&n: &u64| {
+            sleep(Duration::from_micros(50 - n));
+            n * n
+        };
+        let expected = items.iter().map(|n| n * n).collect::<Vec<_>>();
+
+        for jobs in [0, 1, 4, 100] {
+            assert_eq!(map(jobs, &items, square), expected);
+        }
+        assert_eq!(map(4, &[] as &[u64], square), []);
+    }
+}
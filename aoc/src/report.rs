@@ -0,0 +1,328 @@
+use common::InputSource;
+
+use crate::{
+    cache::Cache,
+    days::{Answer, Day},
+    hash::fnv1a,
+    pool,
+};
+
+/// Outcome of running the selected parts of a day on one input.
+pub struct Report {
+    pub day: u8,
+    pub source: InputSource,
+    pub input_hash: Option<u64>,
+    pub answers: Vec<(u8, Answer)>,
+    pub error: Option<String>,
+    /// How the answers came about when not as asked, e.g. a day that cannot
+    /// stream its input.
+    pub note: Option<String>,
+}
+
+impl Report {
+    /// Runs the selected parts of every day on its input, up to `jobs` parts
+    /// at a time. Reports keep the order of `days` whichever part ends first.
+    /// Answers found in `cache` are not solved again, new ones are saved.
+    pub fn run_all(
+        days: Vec<(&Day, InputSource)>,
+        part: Option<u8>,
+        jobs: usize,
+        cache: Option<&Cache>,
+    ) -> Vec<Report> {
+        let inputs = days
+            .iter()
+            .map(|(_, source)| source.read())
+            .collect::<Vec<_>>();
+        let hashes = inputs
+            .iter()
+            .map(|input| input.as_ref().map_or(0, |i| fnv1a(i.as_bytes())))
+            .collect::<Vec<_>>();
+
+        let tasks = days
+            .iter()
+            .zip(&inputs)
+            .enumerate()
+            .filter(|(_, (_, input))| input.is_ok())
+            .flat_map(|(i, ((day, _), _))| [(i, 1, day.part1), (i, 2, day.part2)])
+            .filter(|&(_, number, _)| part.is_none_or(|p| p == number))
+            .collect::<Vec<_>>();
+
+        let mut answers = pool::map(jobs, &tasks, |&(i, number, f)| {
+            let (day, hash) = (days[i].0.number, hashes[i]);
+            if let Some(answer) = cache.and_then(|c| c.get(day, number, hash)) {
+                return Ok(answer);
+            }
+
+            let answer = match &inputs[i] {
+                Ok(input) => f(input),
+                Err(_) => unreachable!("Parts only run on read inputs"),
+            };
+            if let (Some(cache), Ok(answer)) = (cache, &answer) {
+                cache.put(day, number, hash, answer);
+            }
+            answer
+        })
+        .into_iter()
+        .zip(&tasks)
+        .map(|(answer, &(i, number, _))| (i, number, answer))
+        .peekable();
+
+        days.into_iter()
+            .zip(inputs)
+            .enumerate()
+            .map(|(i, ((day, source), input))| {
+                let mut report = Report {
+                    day: day.number,
+                    source,
+                    input_hash: None,
+                    answers: Vec::new(),
+                    error: None,
+                    note: None,
+                };
+
+                match input {
+                    Ok(_) => report.input_hash = Some(hashes[i]),
+                    Err(e) => report.error = Some(e.to_string()),
+                }
+
+                while let Some((_, number, answer)) = answers.next_if(|&(j, _, _)| j == i) {
+                    report.add(number, answer.map_err(|e| e.to_string()));
+                }
+
+                report
+            })
+            .collect()
+    }
+
+    /// Runs like [`Report::run_all`], but every part reads the input of its
+    /// day line by line instead of all at once. There is no input hash, as
+    /// the whole input is never held. Days that cannot stream read their
+    /// input at once instead, which their report notes.
+    pub fn stream_all(
+        days: Vec<(&Day, InputSource)>,
+        part: Option<u8>,
+        jobs: usize,
+    ) -> Vec<Report> {
+        let tasks = days
+            .iter()
+            .enumerate()
+            .flat_map(|(i, (day, _))| {
+                [
+                    (i, 1, day.stream1, day.part1),
+                    (i, 2, day.stream2, day.part2),
+                ]
+            })
+            .filter(|&(_, number, _, _)| part.is_none_or(|p| p == number))
+            .collect::<Vec<_>>();
+
+        // Whether each part streamed, along with its answer.
+        let mut answers = pool::map(jobs, &tasks, |&(i, _, stream, solve)| {
+            let source = &days[i].1;
+            let mut lines = source.lines().map_err(|e| e.to_string())?;
+            match stream(&mut lines) {
+                Some(answer) => {
+                    lines.finish().map_err(|e| e.to_string())?;
+                    Ok((true, answer.map_err(|e| e.to_string())?))
+                }
+                None => {
+                    // Nothing was read yet, and stdin stays locked until then.
+                    drop(lines);
+                    let input = source.read().map_err(|e| e.to_string())?;
+                    Ok((false, solve(&input).map_err(|e| e.to_string())?))
+                }
+            }
+        })
+        .into_iter()
+        .zip(&tasks)
+        .map(|(answer, &(i, number, _, _))| (i, number, answer))
+        .peekable();
+
+        days.into_iter()
+            .enumerate()
+            .map(|(i, (day, source))| {
+                let mut report = Report {
+                    day: day.number,
+                    source,
+                    input_hash: None,
+                    answers: Vec::new(),
+                    error: None,
+                    note: None,
+                };
+
+                while let Some((_, number, answer)) = answers.next_if(|&(j, _, _)| j == i) {
+                    if let Ok((false, _)) = answer {
+                        report.note = Some(format!(
+                            "Day {:02} cannot stream its input, read it at once",
+                            day.number
+                        ));
+                    }
+                    report.add(number, answer.map(|(_, answer)| answer));
+                }
+
+                report
+            })
+            .collect()
+    }
+
+    /// Adds the answer of a part, unless an earlier part failed.
+    fn add(&mut self, part: u8, answer: Result<Answer, String>) {
+        match answer {
+            Ok(answer) if self.error.is_none() => self.answers.push((part, answer)),
+            Ok(_) => {}
+            Err(e) => {
+                self.error.get_or_insert(e);
+            }
+        }
+    }
+}
+
+pub fn print_text(reports: &[Report]) {
+    for r in reports {
+        println!("Day {:02}", r.day);
+
+        for (part, answer) in &r.answers {
+            if answer.cached {
+                println!("Part {}: {} (cached)", part, answer.value);
+            } else {
+                println!(
+                    "Part {}: {} ({:?})",
+                    part,
+                    answer.value,
+                    answer.parse_time + answer.solve_time
+                );
+            }
+        }
+
+        if let Some(note) = &r.note {
+            eprintln!("{}", note);
+        }
+        if let Some(e) = &r.error {
+            eprintln!("{}", e);
+        }
+    }
+}
+
+/// Prints one row per part with the time and allocations of parsing and
+/// solving. Each part parses the input on its own.
+pub fn print_profile(reports: &[Report]) {
+    println!(
+        "{:>3} {:>4} {:>11} {:>8} {:>10} {:>11} {:>8} {:>10}",
+        "Day", "Part", "Parse", "Allocs", "Bytes", "Solve", "Allocs", "Bytes"
+    );
+
+    for r in reports {
+        for (part, answer) in &r.answers {
+            println!(
+                "{:>3} {:>4} {:>11} {:>8} {:>10} {:>11} {:>8} {:>10}",
+                format!("{:02}", r.day),
+                part,
+                format!("{:.1?}", answer.parse_time),
+                answer.parse_allocations.count,
+                answer.parse_allocations.bytes,
+                format!("{:.1?}", answer.solve_time),
+                answer.solve_allocations.count,
+                answer.solve_allocations.bytes,
+            );
+        }
+
+        if let Some(note) = &r.note {
+            eprintln!("{}", note);
+        }
+        if let Some(e) = &r.error {
+            eprintln!("{}", e);
+        }
+    }
+}
+
+/// Prints a JSON array with one object per day. Answers are strings so that
+/// large integers survive any JSON reader; `type` tells how to read them back.
+/// When profiling, parts also tell the allocations of each phase.
+pub fn print_json(reports: &[Report], profile: bool) {
+    let days = reports
+        .iter()
+        .map(|r| {
+            let mut fields = vec![
+                format!("\"day\":{}", r.day),
+                format!("\"input\":{}", json_string(&r.source.to_string())),
+            ];
+
+            if let Some(hash) = r.input_hash {
+                fields.push(format!("\"input_hash\":\"{:016x}\"", hash));
+            }
+
+            let parts = r
+                .answers
+                .iter()
+                .map(|(part, answer)| {
+                    let allocations = if profile {
+                        format!(
+                            ",\"parse_allocs\":{},\"parse_bytes\":{},\"solve_allocs\":{},\"solve_bytes\":{}",
+                            answer.parse_allocations.count,
+                            answer.parse_allocations.bytes,
+                            answer.solve_allocations.count,
+                            answer.solve_allocations.bytes
+                        )
+                    } else {
+                        String::new()
+                    };
+
+                    format!(
+                        "{{\"part\":{},\"answer\":{},\"type\":{},\"parse_ns\":{},\"solve_ns\":{},\"cached\":{}{}}}",
+                        part,
+                        json_string(&answer.value),
+                        json_string(&answer.type_name),
+                        answer.parse_time.as_nanos(),
+                        answer.solve_time.as_nanos(),
+                        answer.cached,
+                        allocations
+                    )
+                })
+                .collect::<Vec<_>>();
+            fields.push(format!("\"parts\":[{}]", parts.join(",")));
+
+            if let Some(note) = &r.note {
+                fields.push(format!("\"note\":{}", json_string(note)));
+            }
+
+            if let Some(e) = &r.error {
+                fields.push(format!("\"error\":{}", json_string(e)));
+            }
+
+            format!("  {{{}}}", fields.join(","))
+        })
+        .collect::<Vec<_>>();
+
+    println!("[\n{}\n]", days.join(",\n"));
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn json_string_test() {
+        assert_eq!(json_string("1228"), "\"1228\"");
+        assert_eq!(
+            json_string("Day 05, line 2: found \"x\"\n\u{1}"),
+            "\"Day 05, line 2: found \\\"x\\\"\\n\\u0001\""
+        );
+    }
+}
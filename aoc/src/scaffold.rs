@@ -0,0 +1,194 @@
+use std::{fs, path::Path};
+
+/// Files of a new day, relative to the repository root, from templates where
+/// `__DD__` is the day on two digits and `__D__` the day itself.
+const FILES: [(&str, &str); 8] = [
+    (
+        "day__DD__/Cargo.toml",
+        include_str!("../templates/Cargo.toml.txt"),
+    ),
+    (
+        "day__DD__/src/main.rs",
+        include_str!("../templates/main.rs.txt"),
+    ),
+    (
+        "day__DD__/src/lib.rs",
+        include_str!("../templates/lib.rs.txt"),
+    ),
+    (
+        "day__DD__/src/generator.rs",
+        include_str!("../templates/generator.rs.txt"),
+    ),
+    (
+        "day__DD__/tests/example.rs",
+        include_str!("../templates/example.rs.txt"),
+    ),
+    (
+        "day__DD__/example.txt",
+        include_str!("../templates/example.txt.txt"),
+    ),
+    (
+        "day__DD__/answers.toml",
+        include_str!("../templates/answers.toml.txt"),
+    ),
+    (
+        "fuzz/fuzz_targets/day__DD__.rs",
+        include_str!("../templates/fuzz_target.rs.txt"),
+    ),
+];
+
+/// Lines registering a day in existing files, each inserted after the line
+/// of the closest previous day.
+const REGISTRIES: [(&str, &str); 4] = [
+    ("Cargo.toml", "    \"day__DD__\","),
+    ("aoc/Cargo.toml", "day__DD__ = { path = \"../day__DD__\" }"),
+    ("aoc/src/days.rs", "    Day::new::<day__DD__::Day__DD__>(),"),
+    ("fuzz/Cargo.toml", "day__DD__ = { path = \"../day__DD__\" }"),
+];
+
+const FUZZ_BIN: &str = "
+[[bin]]
+name = \"day__DD__\"
+path = \"fuzz_targets/day__DD__.rs\"
+test = false
+doc = false
+bench = false
+";
+
+/// Creates the crate of `day` under `root` and registers it in the
+/// workspace, the runner and the fuzz targets. Returns the paths written.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<String>, String> {
+    let crate_dir = root.join(render("day__DD__", day));
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    // Edit the registries in memory first, so that nothing is written when
+    // one of them cannot be updated.
+    let mut edits = Vec::new();
+    for (path, line) in REGISTRIES {
+        let text = read(root, path)?;
+        let mut text = insert_line(&text, line, day).map_err(|e| format!("{}: {}", path, e))?;
+        if path == "fuzz/Cargo.toml" {
+            text.push_str(&render(FUZZ_BIN, day));
+        }
+        edits.push((path.to_string(), text));
+    }
+
+    for (path, template) in FILES {
+        edits.push((render(path, day), render(template, day)));
+    }
+
+    for (path, text) in &edits {
+        let path = root.join(path);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("Cannot create {}: {}", dir.display(), e))?;
+        }
+        fs::write(&path, text).map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+    }
+
+    Ok(edits.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(root: &Path, path: &str) -> Result<String, String> {
+    let path = root.join(path);
+    fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("__DD__", &format!("{:02}", day))
+        .replace("__D__", &day.to_string())
+}
+
+/// Inserts the line of `day` after the line of the closest previous day
+/// found in `text`, keeping days sorted.
+fn insert_line(text: &str, template: &str, day: u8) -> Result<String, String> {
+    let new_line = render(template, day);
+    let lines = text.lines().collect::<Vec<_>>();
+
+    if lines.contains(&new_line.as_str()) {
+        return Err(format!("day {} is already registered", day));
+    }
+
+    let previous = (1..day)
+        .rev()
+        .find_map(|d| {
+            let line = render(template, d);
+            lines.iter().position(|l| *l == line)
+        })
+        .ok_or_else(|| format!("cannot find where to register day {}", day))?;
+
+    let mut lines = lines;
+    lines.insert(previous + 1, &new_line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        assert_eq!(
+            render("    Day::new::<day__DD__::Day__DD__>(), // __D__", 7),
+            "    Day::new::<day07::Day07>(), // 7"
+        );
+    }
+
+    #[test]
+    fn insert_line_test() {
+        let text = "members = [\n    \"day01\",\n    \"day02\",\n    \"grid\",\n]\n";
+
+        assert_eq!(
+            insert_line(text, "    \"day__DD__\",", 13),
+            Ok(
+                "members = [\n    \"day01\",\n    \"day02\",\n    \"day13\",\n    \"grid\",\n]\n"
+                    .to_string()
+            )
+        );
+        assert!(insert_line(text, "    \"day__DD__\",", 2).is_err());
+        assert!(insert_line(text, "    \"day__DD__\",", 1).is_err());
+    }
+
+    #[test]
+    fn templates_test() {
+        let lib = render(FILES[2].1, 13);
+        assert!(lib.contains("pub struct Day13;"));
+        assert!(lib.contains("const DAY: u8 = 13;"));
+        assert!(!lib.contains("__D"));
+    }
+
+    #[test]
+    fn templates_format_test() {
+        use std::{
+            io::Write,
+            process::{Command, Stdio},
+        };
+
+        for (path, template) in FILES.iter().filter(|(p, _)| p.ends_with(".rs")) {
+            let text = render(template, 13);
+            // Toolchains without rustfmt cannot check anything.
+            let Ok(mut rustfmt) = Command::new("rustfmt")
+                .args(["--edition", "2021", "--emit", "stdout"])
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn()
+            else {
+                return;
+            };
+
+            rustfmt
+                .stdin
+                .take()
+                .unwrap()
+                .write_all(text.as_bytes())
+                .unwrap();
+            let output = rustfmt.wait_with_output().unwrap();
+            assert!(output.status.success(), "rustfmt failed on {}", path);
+            assert_eq!(String::from_utf8_lossy(&output.stdout), text, "{}", path);
+        }
+    }
+}
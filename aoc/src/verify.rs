@@ -0,0 +1,89 @@
+use std::fmt;
+
+use crate::{answers::Answers, days::Day, default_input};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+        })
+    }
+}
+
+struct Row {
+    day: u8,
+    part: u8,
+    status: Status,
+    expected: String,
+    actual: String,
+}
+
+/// Runs `days` on their real input, compares against their known answers and
+/// prints a table. Returns whether no answer differs.
+pub fn verify<'a>(days: impl Iterator<Item = &'a Day>) -> bool {
+    let rows = days.flat_map(rows).collect::<Vec<_>>();
+
+    let expected_len = rows.iter().map(|r| r.expected.len()).max().unwrap_or(0);
+    println!(
+        "Day  Part  Status   {:<w$}  Actual",
+        "Expected",
+        w = expected_len.max(8)
+    );
+    for r in &rows {
+        println!(
+            "{:02}   {}     {:<7}  {:<w$}  {}",
+            r.day,
+            r.part,
+            r.status,
+            r.expected,
+            r.actual,
+            w = expected_len.max(8)
+        );
+    }
+
+    rows.iter().all(|r| r.status != Status::Fail)
+}
+
+fn rows(day: &Day) -> Vec<Row> {
+    let answers = Answers::read(day.number);
+    let input = default_input(day.number).read().map_err(|e| e.to_string());
+
+    [(1, day.part1), (2, day.part2)]
+        .into_iter()
+        .map(|(part, f)| {
+            let expected = match &answers {
+                Ok(answers) => answers.get(part).map(str::to_string),
+                Err(e) => Some(e.clone()),
+            };
+            let actual = match &input {
+                Ok(input) => f(input).map(|a| a.value).map_err(|e| e.to_string()),
+                Err(e) => Err(e.clone()),
+            };
+
+            let status = match (&answers, &expected, &actual) {
+                (Err(_), _, _) => Status::Fail,
+                (_, None, _) => Status::Missing,
+                (_, _, Err(_)) => Status::Fail,
+                (_, Some(expected), Ok(actual)) if expected == actual => Status::Pass,
+                _ => Status::Fail,
+            };
+
+            Row {
+                day: day.number,
+                part,
+                status,
+                expected: expected.unwrap_or_default(),
+                actual: actual.unwrap_or_else(|e| e),
+            }
+        })
+        .collect()
+}
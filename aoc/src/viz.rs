@@ -0,0 +1,323 @@
+//! Terminal animations of the days whose state is a map.
+//!
+//! While playing: space pauses or resumes, `n` shows the next frame when
+//! paused, `+` and `-` change the speed and `q` or Ctrl-C quits.
+
+mod day05;
+mod day09;
+mod day11;
+
+use std::{
+    fmt::Write as _,
+    io::{self, IsTerminal, Read, Write},
+    process::{Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use grid::Grid;
+
+/// Days with an animation.
+pub const DAYS: [u8; 3] = [5, 9, 11];
+
+/// Builds the animation of `day` from its raw input.
+pub fn animation(day: u8, input: &str) -> Result<Box<dyn Animation>, String> {
+    let error = |e: common::ParseError| e.to_string();
+
+    match day {
+        5 => Ok(Box::new(day05::Vents::new(input).map_err(error)?)),
+        9 => Ok(Box::new(day09::Basins::new(input).map_err(error)?)),
+        11 => Ok(Box::new(day11::Octopuses::new(input).map_err(error)?)),
+        d => Err(format!("No visualization for day {}", d)),
+    }
+}
+
+/// Sequence of frames showing how a puzzle is solved.
+pub trait Animation {
+    /// Next frame, or None once the animation is over.
+    fn next_frame(&mut self) -> Option<Frame>;
+}
+
+/// Picture of a map with a caption under it.
+pub struct Frame {
+    pub cells: Grid<Cell>,
+    pub caption: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl Cell {
+    pub fn new(glyph: char, color: Color) -> Cell {
+        Cell { glyph, color }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Plain,
+    /// Index in the 256 colors of ANSI terminals.
+    Ansi(u8),
+    /// Bold, in the given color.
+    Bold(u8),
+}
+
+impl Color {
+    /// Gray from 0, nearly black, to 23, nearly white.
+    pub fn gray(level: u8) -> Color {
+        Color::Ansi(232 + level.min(23))
+    }
+
+    fn escape(self) -> String {
+        match self {
+            Color::Plain => "\x1b[0m".to_string(),
+            Color::Ansi(c) => format!("\x1b[0;38;5;{}m", c),
+            Color::Bold(c) => format!("\x1b[1;38;5;{}m", c),
+        }
+    }
+}
+
+/// Draws `frame`, with colors when `ansi` is set.
+pub fn render(frame: &Frame, ansi: bool) -> String {
+    let mut s = String::new();
+
+    for row in frame.cells.rows() {
+        let mut color = Color::Plain;
+        for cell in row {
+            if ansi && cell.color != color {
+                s.push_str(&cell.color.escape());
+                color = cell.color;
+            }
+            s.push(cell.glyph);
+        }
+        if ansi && color != Color::Plain {
+            s.push_str(&Color::Plain.escape());
+        }
+        s.push('\n');
+    }
+
+    let _ = writeln!(s, "{}", frame.caption);
+    s
+}
+
+pub struct Options {
+    /// Frames to show at most, all of them when not set.
+    pub steps: Option<usize>,
+    /// Frames per second when playing.
+    pub fps: u32,
+}
+
+enum Key {
+    Pause,
+    Next,
+    Faster,
+    Slower,
+    Quit,
+}
+
+/// Plays `animation` in the terminal. When the output is not a terminal,
+/// prints every frame one after the other, without colors nor delay.
+pub fn play(animation: &mut dyn Animation, options: &Options) -> io::Result<()> {
+    let frames = (0..options.steps.unwrap_or(usize::MAX)).map_while(|_| animation.next_frame());
+
+    let mut stdout = io::stdout().lock();
+    if !stdout.is_terminal() {
+        for frame in frames {
+            writeln!(stdout, "{}", render(&frame, false))?;
+        }
+        return Ok(());
+    }
+
+    let keys = Keyboard::new();
+    let mut fps = options.fps.max(1);
+    let mut paused = false;
+
+    let _cursor = HiddenCursor::new(&mut stdout)?;
+    write!(stdout, "\x1b[2J")?;
+
+    'frames: for frame in frames {
+        write!(stdout, "\x1b[H{}", render(&frame, true))?;
+        writeln!(
+            stdout,
+            "{} fps{}  [space] pause  [n] next  [+/-] speed  [q] quit\x1b[K",
+            fps,
+            if paused { ", paused" } else { "" }
+        )?;
+        stdout.flush()?;
+
+        let deadline = Instant::now() + Duration::from_secs(1) / fps;
+        loop {
+            let timeout = if paused {
+                Duration::from_secs(3600)
+            } else {
+                deadline.saturating_duration_since(Instant::now())
+            };
+
+            match keys.next(timeout) {
+                Some(Key::Pause) => paused = !paused,
+                Some(Key::Next) if paused => break,
+                Some(Key::Faster) => fps = (fps * 2).min(960),
+                Some(Key::Slower) => fps = (fps / 2).max(1),
+                Some(Key::Quit) => break 'frames,
+                Some(Key::Next) => {}
+                None if paused => {}
+                None => break,
+            }
+            if !paused && deadline <= Instant::now() {
+                break;
+            }
+        }
+    }
+
+    stdout.flush()
+}
+
+/// Hides the cursor of the terminal until dropped, so that it shows again
+/// however the animation ends.
+struct HiddenCursor;
+
+impl HiddenCursor {
+    fn new(out: &mut impl Write) -> io::Result<HiddenCursor> {
+        write!(out, "\x1b[?25l")?;
+        Ok(HiddenCursor)
+    }
+}
+
+impl Drop for HiddenCursor {
+    fn drop(&mut self) {
+        let mut stdout = io::stdout();
+        let _ = write!(stdout, "\x1b[?25h").and_then(|_| stdout.flush());
+    }
+}
+
+/// Keys pressed in the terminal, read one by one without waiting for enter.
+/// The terminal settings are restored when dropped.
+struct Keyboard {
+    keys: Receiver<Key>,
+    settings: Option<String>,
+}
+
+impl Keyboard {
+    fn new() -> Keyboard {
+        let settings = stty(&["-g"]).map(|s| s.trim().to_string());
+        if settings.is_some() {
+            // Without signals, Ctrl-C is read as a key so that quitting
+            // restores the terminal.
+            stty(&["-icanon", "-echo", "-isig", "min", "1"]);
+        }
+
+        let (sender, keys) = mpsc::channel();
+        // Blocked on stdin until the process ends, so it is never joined.
+        thread::spawn(move || {
+            let mut byte = [0];
+            loop {
+                let key = match io::stdin().read(&mut byte) {
+                    Ok(0) => break,
+                    Ok(_) => match byte[0] {
+                        b' ' => Key::Pause,
+                        b'n' => Key::Next,
+                        b'+' | b'=' => Key::Faster,
+                        b'-' => Key::Slower,
+                        // Ctrl-C
+                        b'q' | 0x03 => Key::Quit,
+                        _ => continue,
+                    },
+                    Err(_) => Key::Quit,
+                };
+                if sender.send(key).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Keyboard { keys, settings }
+    }
+
+    /// Next key pressed within `timeout`.
+    fn next(&self, timeout: Duration) -> Option<Key> {
+        match self.keys.recv_timeout(timeout) {
+            Ok(key) => Some(key),
+            Err(RecvTimeoutError::Timeout) => None,
+            // Stdin is closed, nothing can stop the animation anymore.
+            Err(RecvTimeoutError::Disconnected) => {
+                thread::sleep(timeout.min(Duration::from_secs(1)));
+                None
+            }
+        }
+    }
+}
+
+impl Drop for Keyboard {
+    fn drop(&mut self) {
+        if let Some(settings) = &self.settings {
+            stty(&[settings]);
+        }
+    }
+}
+
+/// Runs `stty` on the terminal of stdin, returning its output on success.
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_test() {
+        let frame = Frame {
+            cells: Grid::new(
+                2,
+                2,
+                vec![
+                    Cell::new('a', Color::Plain),
+                    Cell::new('b', Color::Ansi(1)),
+                    Cell::new('c', Color::Bold(2)),
+                    Cell::new('d', Color::Bold(2)),
+                ],
+            ),
+            caption: "Step 1".to_string(),
+        };
+
+        assert_eq!(render(&frame, false), "ab\ncd\nStep 1\n");
+        assert_eq!(
+            render(&frame, true),
+            "a\x1b[0;38;5;1mb\x1b[0m\n\x1b[1;38;5;2mcd\x1b[0m\nStep 1\n"
+        );
+    }
+
+    #[test]
+    fn animation_test() {
+        assert!(animation(1, "199").is_err());
+        assert!(animation(11, "12\n3x").is_err());
+
+        let mut octopuses = animation(11, "11111\n19991\n19191\n19991\n11111").unwrap();
+        let frames = (0..3)
+            .map_while(|_| octopuses.next_frame())
+            .map(|f| render(&f, false))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            frames,
+            [
+                "11111\n19991\n19191\n19991\n11111\nStep 0, 0 flashes\n",
+                "34543\n40004\n50005\n40004\n34543\nStep 1, 9 flashes\n",
+                "45654\n51115\n61116\n51115\n45654\nStep 2, 0 flashes\n",
+            ]
+        );
+    }
+}
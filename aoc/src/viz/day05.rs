@@ -0,0 +1,101 @@
+//! Lines of vents drawn one after the other on the ocean floor.
+
+use std::collections::HashMap;
+
+use common::ParseError;
+use day05::{Line, Point};
+use grid::Grid;
+
+use super::{Animation, Cell, Color, Frame};
+
+/// Largest width of the picture. Larger floors are drawn in blocks of
+/// points, each showing the largest overlap among its points.
+const MAX_WIDTH: usize = 100;
+
+pub struct Vents {
+    lines: Vec<Line>,
+    drawn: usize,
+    /// Lines at each point drawn so far.
+    counts: HashMap<Point, u32>,
+    /// Largest count of each block.
+    blocks: Grid<u32>,
+    /// Points on a side of a block.
+    scale: usize,
+    overlaps: usize,
+}
+
+impl Vents {
+    pub fn new(input: &str) -> Result<Vents, ParseError> {
+        let lines = day05::parse(input)?;
+
+        let max = lines
+            .iter()
+            .flat_map(|&((x0, y0), (x1, y1))| [x0, y0, x1, y1])
+            .max()
+            .unwrap_or(0) as usize;
+        let scale = (max + 1).div_ceil(MAX_WIDTH);
+        let side = (max + 1).div_ceil(scale);
+
+        Ok(Vents {
+            lines,
+            drawn: 0,
+            counts: HashMap::new(),
+            blocks: Grid::new(side, side, vec![0; side * side]),
+            scale,
+            overlaps: 0,
+        })
+    }
+
+    fn block(&self, (x, y): Point) -> (usize, usize) {
+        (x as usize / self.scale, y as usize / self.scale)
+    }
+}
+
+impl Animation for Vents {
+    /// One more line per frame, highlighted.
+    fn next_frame(&mut self) -> Option<Frame> {
+        let &line = self.lines.get(self.drawn)?;
+        self.drawn += 1;
+
+        for point in day05::points(line) {
+            let count = self.counts.entry(point).or_insert(0);
+            *count += 1;
+            if *count == 2 {
+                self.overlaps += 1;
+            }
+            let count = *count;
+
+            let block = self.block(point);
+            self.blocks[block] = self.blocks[block].max(count);
+        }
+
+        let mut cells = self
+            .blocks
+            .iter()
+            .map(|&count| match count {
+                0 => Cell::new('.', Color::gray(4)),
+                1 => Cell::new('1', Color::gray(14)),
+                c => Cell::new(char::from_digit(c.min(9), 10).unwrap(), Color::Ansi(196)),
+            })
+            .collect::<Vec<_>>();
+        let width = self.blocks.width();
+        for point in day05::points(line) {
+            let (x, y) = self.block(point);
+            cells[y * width + x].color = Color::Bold(226);
+        }
+
+        Some(Frame {
+            cells: Grid::new(width, self.blocks.height(), cells),
+            caption: format!(
+                "Line {} of {}, {} overlapping points{}",
+                self.drawn,
+                self.lines.len(),
+                self.overlaps,
+                match self.scale {
+                    1 => String::new(),
+                    s => format!(", {}x{} points per cell", s, s),
+                }
+            ),
+        })
+    }
+}
@@ -0,0 +1,83 @@
+//! Heightmap in shades of gray, basins filling up one after the other from
+//! their low point.
+
+use common::ParseError;
+use grid::Grid;
+
+use super::{Animation, Cell, Color, Frame};
+
+/// Colors of the basins, in turn.
+const PALETTE: [u8; 6] = [39, 41, 178, 170, 208, 75];
+
+pub struct Basins {
+    grid: Grid<u8>,
+    lows: Vec<(usize, usize)>,
+    /// Index of the basin of each point once filled.
+    basins: Grid<Option<usize>>,
+    sizes: Vec<usize>,
+    started: bool,
+}
+
+impl Basins {
+    pub fn new(input: &str) -> Result<Basins, ParseError> {
+        let grid = day09::parse(input)?;
+        let lows = day09::lows(&grid).collect();
+        let basins = Grid::new(grid.width(), grid.height(), vec![None; grid.len()]);
+
+        Ok(Basins {
+            grid,
+            lows,
+            basins,
+            sizes: Vec::new(),
+            started: false,
+        })
+    }
+
+    fn caption(&self) -> String {
+        let mut largest = self.sizes.clone();
+        largest.sort_unstable_by(|a, b| b.cmp(a));
+        largest.truncate(3);
+
+        format!(
+            "Basin {} of {}, largest {:?}, product {}",
+            self.sizes.len(),
+            self.lows.len(),
+            largest,
+            largest.iter().product::<usize>()
+        )
+    }
+}
+
+impl Animation for Basins {
+    /// The heightmap, then one more basin per frame.
+    fn next_frame(&mut self) -> Option<Frame> {
+        if self.started {
+            let &(x, y) = self.lows.get(self.sizes.len())?;
+            let basin = day09::basin(&self.grid, x, y);
+            for &point in &basin {
+                self.basins[point] = Some(self.sizes.len());
+            }
+            self.sizes.push(basin.len());
+        }
+        self.started = true;
+
+        let cells = self
+            .grid
+            .positions()
+            .map(|p| {
+                let height = self.grid[p];
+                let glyph = char::from(b'0' + height);
+                match self.basins[p] {
+                    Some(b) if p == self.lows[b] => Cell::new(glyph, Color::Bold(PALETTE[b % 6])),
+                    Some(b) => Cell::new(glyph, Color::Ansi(PALETTE[b % 6])),
+                    None => Cell::new(glyph, Color::gray(2 * height)),
+                }
+            })
+            .collect();
+
+        Some(Frame {
+            cells: Grid::new(self.grid.width(), self.grid.height(), cells),
+            caption: self.caption(),
+        })
+    }
+}
@@ -0,0 +1,57 @@
+//! Octopuses gaining energy step after step, the flashing ones highlighted.
+
+use common::ParseError;
+use grid::Grid;
+
+use super::{Animation, Cell, Color, Frame};
+
+pub struct Octopuses {
+    grid: Grid<u8>,
+    step: usize,
+    flashes: usize,
+    over: bool,
+}
+
+impl Octopuses {
+    pub fn new(input: &str) -> Result<Octopuses, ParseError> {
+        Ok(Octopuses {
+            grid: day11::parse(input)?,
+            step: 0,
+            flashes: 0,
+            over: false,
+        })
+    }
+}
+
+impl Animation for Octopuses {
+    /// The octopuses before the first step, then after every step until they
+    /// all flash at once.
+    fn next_frame(&mut self) -> Option<Frame> {
+        if self.over {
+            return None;
+        }
+
+        if 0 < self.step {
+            let (grid, flashes) = day11::step(self.grid.clone());
+            self.grid = grid;
+            self.flashes = flashes;
+        }
+        self.over = self.flashes == self.grid.len() || self.step == day11::MAX_STEPS;
+
+        let cells = self
+            .grid
+            .iter()
+            .map(|&energy| match energy {
+                0 if 0 < self.step => Cell::new('0', Color::Bold(226)),
+                e => Cell::new(char::from(b'0' + e), Color::gray(4 + 2 * e)),
+            })
+            .collect();
+        let frame = Frame {
+            cells: Grid::new(self.grid.width(), self.grid.height(), cells),
+            caption: format!("Step {}, {} flashes", self.step, self.flashes),
+        };
+
+        self.step += 1;
+        Some(frame)
+    }
+}
@@ -0,0 +1,216 @@
+use std::{
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::PathBuf,
+};
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    /// Interprets `--input <path>`, where `-` stands for stdin, and
+    /// `--inline <text>`. Returns `None` for any other flag.
+    pub fn from_flag(flag: &str, value: String) -> Option<InputSource> {
+        match flag {
+            "--input" if value == "-" => Some(InputSource::Stdin),
+            "--input" => Some(InputSource::File(value.into())),
+            "--inline" => Some(InputSource::Inline(value)),
+            _ => None,
+        }
+    }
+
+    /// Parses the command line of a day binary, defaulting to `input.txt`.
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Result<InputSource, String> {
+        let mut source = InputSource::File("input.txt".into());
+
+        while let Some(flag) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("Missing value for {}", flag))?;
+            source = InputSource::from_flag(&flag, value)
+                .ok_or_else(|| format!("Unknown flag {}", flag))?;
+        }
+
+        Ok(source)
+    }
+
+    /// Lines of the input, read one at a time through a buffer so that
+    /// inputs larger than memory can be processed.
+    pub fn lines(&self) -> Result<Lines, InputError> {
+        let reader: Box<dyn BufRead> = match self {
+            InputSource::File(path) => match File::open(path) {
+                Ok(file) => Box::new(BufReader::new(file)),
+                Err(error) => {
+                    return Err(InputError {
+                        source: self.clone(),
+                        error,
+                    })
+                }
+            },
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+            InputSource::Inline(text) => Box::new(Cursor::new(text.clone().into_bytes())),
+        };
+
+        Ok(Lines {
+            source: self.clone(),
+            reader,
+            error: None,
+        })
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let read = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+            InputSource::Inline(text) => Ok(text.clone()),
+        };
+
+        read.map_err(|error| InputError {
+            source: self.clone(),
+            error,
+        })
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "file {}", path.display()),
+            InputSource::Stdin => write!(f, "standard input"),
+            InputSource::Inline(_) => write!(f, "inline input"),
+        }
+    }
+}
+
+/// Lines of an input without their line ending, as [`str::lines`] splits
+/// them. Iteration stops at the first read error, returned by
+/// [`Lines::finish`].
+pub struct Lines {
+    source: InputSource,
+    reader: Box<dyn BufRead>,
+    error: Option<io::Error>,
+}
+
+impl Lines {
+    /// Whether every line was read, once iteration is over.
+    pub fn finish(self) -> Result<(), InputError> {
+        match self.error {
+            Some(error) => Err(InputError {
+                source: self.source,
+                error,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Iterator for Lines {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => None,
+            Ok(_) => {
+                if line.ends_with('\n') {
+                    line.pop();
+                    if line.ends_with('\r') {
+                        line.pop();
+                    }
+                }
+                Some(line)
+            }
+            Err(e) => {
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
+/// Input that could not be read, with where it was read from.
+#[derive(Debug)]
+pub struct InputError {
+    pub source: InputSource,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cannot read input {}: {}", self.source, self.error)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(s: &str) -> impl Iterator<Item = String> + '_ {
+        s.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn from_args_test() {
+        assert_eq!(
+            InputSource::from_args(args("")),
+            Ok(InputSource::File("input.txt".into()))
+        );
+        assert_eq!(
+            InputSource::from_args(args("--input -")),
+            Ok(InputSource::Stdin)
+        );
+        assert_eq!(
+            InputSource::from_args(args("--inline 3,4,3,1,2")),
+            Ok(InputSource::Inline("3,4,3,1,2".to_string()))
+        );
+        assert!(InputSource::from_args(args("--input")).is_err());
+        assert!(InputSource::from_args(args("--output x")).is_err());
+    }
+
+    #[test]
+    fn lines_test() {
+        let text = "1\r\n2\n\n3";
+        let mut lines = InputSource::Inline(text.to_string()).lines().unwrap();
+        assert_eq!(
+            lines.by_ref().collect::<Vec<_>>(),
+            text.lines().collect::<Vec<_>>()
+        );
+        assert!(lines.finish().is_ok());
+
+        assert!(InputSource::File("missing/input.txt".into())
+            .lines()
+            .is_err());
+    }
+
+    #[test]
+    fn read_missing_file_test() {
+        let error = InputSource::File("missing/input.txt".into())
+            .read()
+            .unwrap_err();
+
+        assert_eq!(error.error.kind(), io::ErrorKind::NotFound);
+        assert!(error
+            .to_string()
+            .starts_with("Cannot read input file missing/input.txt: "));
+    }
+}
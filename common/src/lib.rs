@@ -0,0 +1,381 @@
+mod input;
+mod rng;
+
+use std::{env, error::Error, fmt, fmt::Display, process};
+
+pub use input::{InputError, InputSource, Lines};
+pub use rng::Rng;
+
+/// A puzzle of the season: how to read its input and how to answer both parts.
+pub trait Solution {
+    /// Day of the puzzle, from 1 to 25.
+    const DAY: u8;
+
+    /// Title, input format and example of the puzzle.
+    const METADATA: Metadata;
+
+    /// Parsed puzzle input, which may borrow from the raw text.
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    /// Every problem found in `input`, where [`Solution::parse`] stops at the
+    /// first one. Days without a validator of their own report that one.
+    fn check(input: &str) -> Vec<ParseError> {
+        Self::parse(input).err().into_iter().collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError>;
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError>;
+
+    /// Answer of part 1 from `lines` read one at a time, in memory bounded by
+    /// the state of the puzzle rather than by the size of the input. `None`
+    /// for days that need their whole input at once.
+    fn stream1(
+        lines: &mut dyn Iterator<Item = String>,
+    ) -> Option<Result<Self::Answer1, PuzzleError>> {
+        let _ = lines;
+        None
+    }
+
+    /// Answer of part 2 from `lines` read one at a time, as
+    /// [`Solution::stream1`].
+    fn stream2(
+        lines: &mut dyn Iterator<Item = String>,
+    ) -> Option<Result<Self::Answer2, PuzzleError>> {
+        let _ = lines;
+        None
+    }
+}
+
+/// Feeds `solve` with the values `parse` reads from each of `lines`, up to the
+/// first line that does not parse. `parse` sees each line as a whole input,
+/// its errors are moved to the line number.
+pub fn stream<T, A>(
+    lines: &mut dyn Iterator<Item = String>,
+    mut parse: impl FnMut(String) -> Result<T, ParseError>,
+    solve: impl FnOnce(&mut dyn Iterator<Item = T>) -> Result<A, SolveError>,
+) -> Result<A, PuzzleError> {
+    let mut error = None;
+    let mut values = lines.enumerate().map_while(|(i, l)| match parse(l) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            error = Some(e.on_line(i + 1));
+            None
+        }
+    });
+
+    let answer = solve(&mut values);
+    drop(values);
+
+    match error {
+        // Answers of a truncated input are meaningless.
+        Some(e) => Err(e.into()),
+        None => Ok(answer?),
+    }
+}
+
+/// What a puzzle is about, for listings and the example tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    /// Title of the puzzle statement, e.g. `Sonar Sweep`.
+    pub title: &'static str,
+    /// Summary of what the input looks like.
+    pub input: &'static str,
+    /// Example input of the puzzle statement.
+    pub example: &'static str,
+    /// Answers of both parts on the example.
+    pub answers: [&'static str; 2],
+}
+
+/// Checks a puzzle against the example of its metadata: the example has no
+/// problem and both parts give the expected answers, whether the input is
+/// read at once or streamed. Panics otherwise, for use in tests.
+pub fn test_example<S: Solution>() {
+    let Metadata {
+        example, answers, ..
+    } = S::METADATA;
+
+    assert_eq!(S::check(example), [], "Day {:02}: invalid example", S::DAY);
+
+    let input = S::parse(example).unwrap_or_else(|e| panic!("{}", e));
+    let answer1 = S::part1(&input).map(|a| a.to_string());
+    let answer2 = S::part2(&input).map(|a| a.to_string());
+    assert_eq!(
+        answer1,
+        Ok(answers[0].to_string()),
+        "Day {:02}, part 1",
+        S::DAY
+    );
+    assert_eq!(
+        answer2,
+        Ok(answers[1].to_string()),
+        "Day {:02}, part 2",
+        S::DAY
+    );
+
+    let lines = || example.lines().map(String::from);
+    if let Some(answer) = S::stream1(&mut lines()) {
+        let answer = answer.map(|a| a.to_string());
+        assert_eq!(
+            answer,
+            Ok(answers[0].to_string()),
+            "Day {:02}, part 1 streamed",
+            S::DAY
+        );
+    }
+    if let Some(answer) = S::stream2(&mut lines()) {
+        let answer = answer.map(|a| a.to_string());
+        assert_eq!(
+            answer,
+            Ok(answers[1].to_string()),
+            "Day {:02}, part 2 streamed",
+            S::DAY
+        );
+    }
+}
+
+/// Random inputs of a puzzle, valid for [`Solution::parse`].
+pub trait Generator: Solution {
+    /// Largest `size` that [`Generator::generate`] honors, larger ones being
+    /// capped to it, e.g. because larger inputs take too long to solve.
+    const MAX_SIZE: usize = usize::MAX;
+
+    /// Generates an input from `rng` whose length grows with `size`, e.g. its
+    /// number of lines or the side of its grid.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Entry point of a day binary: reads the input selected on the command line
+/// and prints the answers of both parts.
+pub fn run<S: Solution>() {
+    let source = InputSource::from_args(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!(
+            "{}\nUsage: day{:02} [--input <path|->] [--inline <text>]",
+            e,
+            S::DAY
+        );
+        process::exit(2)
+    });
+
+    run_on::<S>(&source);
+}
+
+/// Prints the answers of both parts on the input of `source`, for day
+/// binaries with options of their own. Exits the process on errors.
+pub fn run_on<S: Solution>(source: &InputSource) {
+    let input = source.read().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1)
+    });
+
+    let input = S::parse(&input).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1)
+    });
+
+    let answers = [
+        S::part1(&input).map(|a| a.to_string()),
+        S::part2(&input).map(|a| a.to_string()),
+    ];
+
+    let mut failed = false;
+    for (part, answer) in (1..).zip(answers) {
+        match answer {
+            Ok(answer) => println!("Part {}: {}", part, answer),
+            Err(e) => {
+                eprintln!("{}", e);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// Malformed puzzle input, located by 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Builds an error pointing at `token`, which must be a slice of `input`.
+    /// An empty `token` points at the position where something was missing.
+    pub fn at(day: u8, input: &str, token: &str, expected: impl Into<String>) -> ParseError {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + token.len() <= input.len())
+            .expect("Token is not a slice of the input");
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError {
+            day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: token.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Moves an error found in a line parsed on its own to line `number` of
+    /// the whole input.
+    pub fn on_line(self, number: usize) -> ParseError {
+        ParseError {
+            line: number + self.line - 1,
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {:02}, line {}, column {}: expected {}, found ",
+            self.day, self.line, self.column, self.expected
+        )?;
+
+        if self.text.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "{:?}", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Input that parses but has no answer, e.g. bingo boards none of which wins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub day: u8,
+    pub part: u8,
+    pub reason: String,
+}
+
+impl SolveError {
+    pub fn new(day: u8, part: u8, reason: impl Into<String>) -> SolveError {
+        SolveError {
+            day,
+            part,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {:02}, part {}: {}",
+            self.day, self.part, self.reason
+        )
+    }
+}
+
+impl Error for SolveError {}
+
+/// Why a part of a puzzle has no answer, whether its input cannot be read or
+/// cannot be solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    Parse(ParseError),
+    Solve(SolveError),
+}
+
+impl From<ParseError> for PuzzleError {
+    fn from(e: ParseError) -> PuzzleError {
+        PuzzleError::Parse(e)
+    }
+}
+
+impl From<SolveError> for PuzzleError {
+    fn from(e: SolveError) -> PuzzleError {
+        PuzzleError::Solve(e)
+    }
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PuzzleError::Parse(e) => e.fmt(f),
+            PuzzleError::Solve(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for PuzzleError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_error_at_test() {
+        let input = "1,2\n3,x4\n";
+        let error = ParseError::at(7, input, &input[6..8], "integer");
+
+        assert_eq!(
+            error,
+            ParseError {
+                day: 7,
+                line: 2,
+                column: 3,
+                text: "x4".to_string(),
+                expected: "integer".to_string(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Day 07, line 2, column 3: expected integer, found \"x4\""
+        );
+    }
+
+    #[test]
+    fn parse_error_at_missing_test() {
+        let input = "a-";
+        let error = ParseError::at(12, input, &input[2..], "cave name");
+
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(
+            error.to_string(),
+            "Day 12, line 1, column 3: expected cave name, found nothing"
+        );
+    }
+
+    #[test]
+    fn stream_test() {
+        let parse = |l: String| {
+            l.parse::<u64>()
+                .map_err(|_| ParseError::at(1, &l, &l, "integer"))
+        };
+        let sum = |values: &mut dyn Iterator<Item = u64>| Ok(values.sum::<u64>());
+        let lines = |s: &str| s.lines().map(String::from).collect::<Vec<_>>().into_iter();
+
+        assert_eq!(stream(&mut lines("1\n2\n3"), parse, sum), Ok(6));
+
+        let error = stream(&mut lines("1\n2\nx\n4"), parse, sum).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Day 01, line 3, column 1: expected integer, found \"x\""
+        );
+
+        let fail = |_: &mut dyn Iterator<Item = u64>| Err::<u64, _>(SolveError::new(1, 1, "no"));
+        assert_eq!(
+            stream(&mut lines("1"), parse, fail),
+            Err(PuzzleError::Solve(SolveError::new(1, 1, "no")))
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use std::ops::Range;
+
+/// Small seeded pseudo-random generator (SplitMix64), so that generated
+/// inputs are reproducible from their seed on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "Cannot pick in an empty range");
+        let len = (range.end - range.start) as u128;
+        range.start + ((self.next_u64() as u128 * len) >> 64) as usize
+    }
+
+    /// True with probability `numerator / denominator`.
+    pub fn ratio(&mut self, numerator: usize, denominator: usize) -> bool {
+        self.range(0..denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, values: &'a [T]) -> &'a T {
+        &values[self.range(0..values.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            values.swap(i, self.range(0..i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn seed_test() {
+        let mut a = Rng::new(2021);
+        let mut b = Rng::new(2021);
+        let mut c = Rng::new(2022);
+
+        let a = (0..8).map(|_| a.next_u64()).collect::<Vec<_>>();
+        let b = (0..8).map(|_| b.next_u64()).collect::<Vec<_>>();
+        let c = (0..8).map(|_| c.next_u64()).collect::<Vec<_>>();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn range_test() {
+        let mut rng = Rng::new(0);
+        let mut seen = [false; 5];
+
+        for _ in 0..1000 {
+            let n = rng.range(3..8);
+            assert!((3..8).contains(&n));
+            seen[n - 3] = true;
+        }
+
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn shuffle_test() {
+        let mut rng = Rng::new(0);
+        let mut values = (0..100).collect::<Vec<_>>();
+        rng.shuffle(&mut values);
+
+        assert_ne!(values, (0..100).collect::<Vec<_>>());
+        values.sort_unstable();
+        assert_eq!(values, (0..100).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt;
+
+use crate::{slide, Reading, WindowError};
+
+/// Limits beyond which readings are suspicious. Each kind of anomaly is only
+/// looked for when its limit is set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+    /// Readings before each one that its z-score is computed over.
+    pub window: usize,
+    /// Largest z-score of a reading that is not a spike.
+    pub z_score: Option<f64>,
+    /// Largest change between consecutive readings that is not a jump.
+    pub jump: Option<f64>,
+    /// Fewest equal consecutive readings that make a flatline. Below 2, every
+    /// reading is a flatline on its own.
+    pub flatline: Option<usize>,
+}
+
+impl Default for Thresholds {
+    /// Spikes over 3 standard deviations of the last 10 readings and
+    /// flatlines of 5 readings. Jumps depend on the scale of the readings.
+    fn default() -> Thresholds {
+        Thresholds {
+            window: 10,
+            z_score: Some(3.0),
+            jump: None,
+            flatline: Some(5),
+        }
+    }
+}
+
+/// Suspicious reading.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anomaly {
+    /// Index of the reading, or of the first one of a flatline.
+    pub index: usize,
+    /// What is suspicious about it.
+    pub reason: Reason,
+}
+
+/// Why a reading is suspicious.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reason {
+    /// Reading far from the previous ones, with its z-score over them.
+    Spike {
+        /// Number of standard deviations from their mean, negative below it.
+        z_score: f64,
+    },
+    /// Large change from the previous reading.
+    Jump {
+        /// Previous reading.
+        from: Reading,
+        /// This reading.
+        to: Reading,
+    },
+    /// Same reading over and over, as from a stuck sensor.
+    Flatline {
+        /// Number of equal readings.
+        len: usize,
+    },
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reason::Spike { z_score } => write!(f, "spike, z-score {:+.2}", z_score),
+            Reason::Jump { from, to } => write!(f, "jump from {} to {}", from, to),
+            Reason::Flatline { len } => write!(f, "flatline of {} readings", len),
+        }
+    }
+}
+
+/// Anomalies of `readings` in the order of their index, spikes before jumps
+/// before flatlines on the same index. Spikes are only looked for once a
+/// whole window precedes a reading, so there are none in inputs no longer
+/// than the window, and not after windows of equal readings which have no
+/// deviation.
+pub fn detect(readings: &[Reading], thresholds: &Thresholds) -> Result<Vec<Anomaly>, WindowError> {
+    let mut anomalies = Vec::new();
+
+    if let Some(limit) = thresholds.z_score {
+        let window = thresholds.window;
+        if window == 0 {
+            return Err(WindowError::Empty);
+        }
+
+        // Same windows as solve: the previous readings and the current one.
+        for (i, (previous, reading)) in slide(readings, window).enumerate() {
+            let reading = reading.as_f64();
+            let mean = previous.iter().map(|r| r.as_f64()).sum::<f64>() / window as f64;
+            let variance = previous
+                .iter()
+                .map(|r| (r.as_f64() - mean).powi(2))
+                .sum::<f64>()
+                / window as f64;
+
+            let z_score = (reading - mean) / variance.sqrt();
+            if variance > 0.0 && z_score.abs() > limit {
+                anomalies.push(Anomaly {
+                    index: i + window,
+                    reason: Reason::Spike { z_score },
+                });
+            }
+        }
+    }
+
+    if let Some(limit) = thresholds.jump {
+        for (i, w) in readings.windows(2).enumerate() {
+            if w[1].rises_over(w[0], limit) || w[0].rises_over(w[1], limit) {
+                anomalies.push(Anomaly {
+                    index: i + 1,
+                    reason: Reason::Jump {
+                        from: w[0],
+                        to: w[1],
+                    },
+                });
+            }
+        }
+    }
+
+    if let Some(limit) = thresholds.flatline {
+        let mut start = 0;
+        for i in 1..=readings.len() {
+            if i < readings.len() && readings[i].same(readings[start]) {
+                continue;
+            }
+            let len = i - start;
+            if len >= limit {
+                anomalies.push(Anomaly {
+                    index: start,
+                    reason: Reason::Flatline { len },
+                });
+            }
+            start = i;
+        }
+    }
+
+    // Stable, so that reasons on the same index keep their order.
+    anomalies.sort_by_key(|a| a.index);
+    Ok(anomalies)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn detect_test() {
+        let readings = [10, 11, 10, 11, 30, 12, 12, 12, 12, 11].map(Reading::Integer);
+
+        let thresholds = Thresholds {
+            window: 4,
+            z_score: Some(3.0),
+            jump: Some(10.0),
+            flatline: Some(4),
+        };
+
+        let anomalies = detect(&readings, &thresholds).unwrap();
+        let reasons = anomalies
+            .iter()
+            .map(|a| format!("{}: {}", a.index, a.reason))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            [
+                "4: spike, z-score +39.00",
+                "4: jump from 11 to 30",
+                "5: jump from 30 to 12",
+                "5: flatline of 4 readings",
+            ]
+        );
+
+        // The flat window before the last reading has no deviation.
+        let thresholds = Thresholds {
+            jump: None,
+            flatline: None,
+            ..thresholds
+        };
+        assert_eq!(detect(&readings[5..], &thresholds), Ok(vec![]));
+        assert_eq!(detect(&readings[..3], &thresholds), Ok(vec![]));
+        assert_eq!(detect(&readings[..4], &thresholds), Ok(vec![]));
+        assert_eq!(
+            detect(
+                &readings,
+                &Thresholds {
+                    window: 0,
+                    ..thresholds
+                }
+            ),
+            Err(WindowError::Empty)
+        );
+
+        assert_eq!(
+            detect(
+                &readings[..3],
+                &Thresholds {
+                    z_score: None,
+                    ..thresholds
+                }
+            ),
+            Ok(vec![])
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use common::{Generator, Rng};
+
+use crate::Day01;
+
+impl Generator for Day01 {
+    /// `size` depths of a random walk going deeper more often than not.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut depth = rng.range(100..200);
+
+        (0..size)
+            .map(|_| {
+                depth = (depth + rng.range(0..40)).saturating_sub(15);
+                format!("{}\n", depth)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,335 @@
+//! Day 1: Sonar Sweep. Counts how often the sea floor depth increases.
+
+#![warn(missing_docs)]
+
+mod anomalies;
+mod generator;
+mod readings;
+mod report;
+
+use std::{error::Error, fmt};
+
+use common::{Metadata, ParseError, PuzzleError, Solution, SolveError};
+
+pub use anomalies::{detect, Anomaly, Reason, Thresholds};
+pub use readings::{count_increases_by, parse_readings, Missing, Reading};
+pub use report::{Change, DepthReport, Direction, Run};
+
+/// Solver of day 1, for the runner and generic code over [`Solution`].
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    const METADATA: Metadata = Metadata {
+        title: "Sonar Sweep",
+        input: "one unsigned integer depth per line",
+        example: include_str!("../example.txt"),
+        answers: ["7", "5"],
+    };
+
+    type Input<'a> = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(input))
+    }
+
+    fn stream1(lines: &mut dyn Iterator<Item = String>) -> Option<Result<usize, PuzzleError>> {
+        Some(common::stream(lines, parse_depth, |depths| {
+            Ok(solve_stream(depths, 2))
+        }))
+    }
+
+    fn stream2(lines: &mut dyn Iterator<Item = String>) -> Option<Result<usize, PuzzleError>> {
+        Some(common::stream(lines, parse_depth, |depths| {
+            Ok(solve_stream(depths, 4))
+        }))
+    }
+}
+
+/// Reads one depth per line.
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    input.lines().map(|l| parse_line(input, l)).collect()
+}
+
+/// Every line [`parse`] would stop at.
+pub fn check(input: &str) -> Vec<ParseError> {
+    input
+        .lines()
+        .filter_map(|l| parse_line(input, l).err())
+        .collect()
+}
+
+/// Reads the depth of a single line, for inputs read line by line.
+pub fn parse_depth(l: String) -> Result<u64, ParseError> {
+    parse_line(&l, &l)
+}
+
+fn parse_line(input: &str, l: &str) -> Result<u64, ParseError> {
+    l.parse()
+        .map_err(|_| ParseError::at(Day01::DAY, input, l, "unsigned integer"))
+}
+
+/// Number of depths deeper than the previous one.
+pub fn part1(s: &[u64]) -> usize {
+    solve(s, 2)
+}
+
+/// Number of sums of three consecutive depths larger than the previous sum.
+pub fn part2(s: &[u64]) -> usize {
+    // a + b + c < b + c + d <=> a < d
+    solve(s, 4)
+}
+
+/// Number of sums of `window` consecutive depths larger than the previous
+/// sum, for windows of at least one depth and at most all of them.
+pub fn count_increases(depths: &[u64], window: usize) -> Result<usize, WindowError> {
+    check_window(depths.len(), window)?;
+
+    // Consecutive sums share all but the first and the last depth.
+    Ok(solve(depths, window + 1))
+}
+
+/// Checks that `window` fits in an input of `depths` depths.
+fn check_window(depths: usize, window: usize) -> Result<(), WindowError> {
+    if window == 0 {
+        return Err(WindowError::Empty);
+    }
+    if depths < window {
+        return Err(WindowError::TooLarge { window, depths });
+    }
+    Ok(())
+}
+
+/// Window size that [`count_increases`], [`count_increases_by`] and
+/// [`DepthReport`] cannot slide over the depths. [`detect`] only fails on
+/// empty windows, as inputs shorter than its window merely have no spike.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WindowError {
+    /// Window of no depth.
+    Empty,
+    /// Window of more depths than the input holds.
+    TooLarge {
+        /// Depths in a window.
+        window: usize,
+        /// Depths in the input.
+        depths: usize,
+    },
+}
+
+impl fmt::Display for WindowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WindowError::Empty => write!(f, "windows must hold at least one depth"),
+            WindowError::TooLarge { window, depths } => write!(
+                f,
+                "window of {} depths larger than the {} depths of the input",
+                window, depths
+            ),
+        }
+    }
+}
+
+impl Error for WindowError {}
+
+fn solve(s: &[u64], n: usize) -> usize {
+    slide(s, n - 1).filter(|(w, last)| w[0] < **last).count()
+}
+
+/// Each item after the first `window` ones, with the `window` items before
+/// it. Consecutive windows differ by the first of those and the item itself.
+fn slide<T>(items: &[T], window: usize) -> impl Iterator<Item = (&[T], &T)> {
+    items
+        .windows(window + 1)
+        .map(move |w| (&w[..window], &w[window]))
+}
+
+/// Counter of increases over windows of depths read one at a time, for
+/// inputs too large to hold in memory or that never end. Keeps only the last
+/// window of depths, and counts as [`count_increases`] on the depths read.
+#[derive(Debug, Clone)]
+pub struct Sweep {
+    /// Last depths read, the oldest at `next` once full.
+    ring: Vec<u64>,
+    window: usize,
+    next: usize,
+    count: usize,
+}
+
+impl Sweep {
+    /// Counter over windows of `window` depths.
+    pub fn new(window: usize) -> Result<Sweep, WindowError> {
+        if window == 0 {
+            return Err(WindowError::Empty);
+        }
+        Ok(Sweep::with_window(window))
+    }
+
+    fn with_window(window: usize) -> Sweep {
+        Sweep {
+            ring: Vec::with_capacity(window),
+            window,
+            next: 0,
+            count: 0,
+        }
+    }
+
+    /// Reads the next depth and returns the count of increases so far.
+    pub fn push(&mut self, depth: u64) -> usize {
+        if self.ring.len() < self.window {
+            self.ring.push(depth);
+        } else {
+            // Same comparison as solve: only the first and last depths of
+            // consecutive windows differ.
+            if self.ring[self.next] < depth {
+                self.count += 1;
+            }
+            self.ring[self.next] = depth;
+            self.next = (self.next + 1) % self.window;
+        }
+        self.count
+    }
+
+    /// Count of increases over the depths read so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Running count of increases after each of `depths`.
+    pub fn running<I>(mut self, depths: I) -> impl Iterator<Item = usize>
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        depths.into_iter().map(move |depth| self.push(depth))
+    }
+}
+
+/// Same as [`solve`], keeping only the last `n - 1` depths.
+fn solve_stream(depths: &mut dyn Iterator<Item = u64>, n: usize) -> usize {
+    let mut sweep = Sweep::with_window(n - 1);
+    depths.for_each(|depth| {
+        sweep.push(depth);
+    });
+    sweep.count()
+}
+
+#[cfg(test)]
+mod test {
+    use common::{Generator, Rng};
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("199\n200\n-208\n").unwrap_err();
+
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "-208");
+    }
+
+    #[test]
+    fn check_test() {
+        let errors = check("199\n-200\n208\nx\n");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column, e.expected.as_str()))
+                .collect::<Vec<_>>(),
+            [(2, 1, "unsigned integer"), (4, 1, "unsigned integer")]
+        );
+    }
+
+    #[test]
+    fn sweep_test() {
+        let depths = parse(Day01::METADATA.example).unwrap();
+        let running = Sweep::new(1).unwrap().running(depths).collect::<Vec<_>>();
+
+        assert_eq!(running, [0, 1, 2, 3, 3, 4, 5, 6, 6, 7]);
+        assert_eq!(Sweep::new(0).unwrap_err(), WindowError::Empty);
+    }
+
+    #[test]
+    fn count_increases_window_test() {
+        let depths = parse(Day01::METADATA.example).unwrap();
+
+        assert_eq!(count_increases(&depths, 1), Ok(7));
+        assert_eq!(count_increases(&depths, 3), Ok(5));
+        assert_eq!(count_increases(&depths, 10), Ok(0));
+        assert_eq!(count_increases(&depths, 0), Err(WindowError::Empty));
+        assert_eq!(
+            count_increases(&depths, 11),
+            Err(WindowError::TooLarge {
+                window: 11,
+                depths: 10
+            })
+        );
+    }
+
+    /// Counts increases of the actual sums of `window` consecutive depths.
+    fn reference(depths: &[u64], window: usize) -> usize {
+        let sums = depths
+            .windows(window)
+            .map(|w| w.iter().sum::<u64>())
+            .collect::<Vec<_>>();
+        sums.windows(2).filter(|s| s[0] < s[1]).count()
+    }
+
+    proptest! {
+        #[test]
+        fn part1_reference_test(depths in vec(0u64..10_000, 0..200)) {
+            prop_assert_eq!(part1(&depths), reference(&depths, 1));
+        }
+
+        #[test]
+        fn part2_reference_test(depths in vec(0u64..10_000, 0..200)) {
+            prop_assert_eq!(part2(&depths), reference(&depths, 3));
+        }
+
+        #[test]
+        fn solve_stream_test(depths in vec(0u64..10_000, 0..200), n in 2usize..6) {
+            prop_assert_eq!(solve_stream(&mut depths.iter().copied(), n), solve(&depths, n));
+        }
+
+        #[test]
+        fn count_increases_test(depths in vec(0u64..10_000, 0..200), window in 1usize..20) {
+            match count_increases(&depths, window) {
+                Ok(count) => prop_assert_eq!(count, reference(&depths, window)),
+                Err(e) => prop_assert_eq!(e, WindowError::TooLarge { window, depths: depths.len() }),
+            }
+        }
+
+        #[test]
+        fn sweep_running_test(depths in vec(0u64..10_000, 0..200), window in 1usize..20) {
+            let running = Sweep::new(window).unwrap().running(depths.clone()).collect::<Vec<_>>();
+            prop_assert_eq!(running.len(), depths.len());
+            for (i, &count) in running.iter().enumerate() {
+                prop_assert_eq!(count, reference(&depths[..=i], window));
+            }
+        }
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day01::generate(&mut Rng::new(seed), 100);
+            let input = Day01::parse(&input).unwrap();
+            Day01::part1(&input).unwrap();
+            Day01::part2(&input).unwrap();
+        }
+    }
+}
@@ -1,26 +1,253 @@
-use std::fs::read_to_string;
+use std::{env, fmt::Display, process};
+
+use common::{InputSource, Solution};
+use day01::{Day01, DepthReport, Missing, Sweep, Thresholds};
+
+const USAGE: &str = "Usage: day01 [--input <path|->] [--inline <text>] [--window <size>] \
+    [--follow | --report <table|json> | --tolerance <epsilon> --missing <reject|skip|hold> | \
+    --anomalies [--z-score <z>] [--jump <change>] [--flatline <length>]]";
+
+/// Command line of day 1, which can count increases over windows of any size
+/// on top of the flags of every day.
+struct Options {
+    source: InputSource,
+    window: Option<usize>,
+    /// Prints the running count after each depth, reading them as they come.
+    follow: bool,
+    /// Prints every change of depth instead of the count of increases.
+    report: Option<Format>,
+    /// Smallest change counted as an increase, for signed and fractional
+    /// readings.
+    tolerance: Option<f64>,
+    /// Handling of missing readings, which also allows signed and fractional
+    /// readings.
+    missing: Option<Missing>,
+    /// Lists suspicious readings instead of counting increases.
+    anomalies: Option<Thresholds>,
+}
+
+enum Format {
+    Table,
+    Json,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+        let mut options = Options {
+            source: InputSource::File("input.txt".into()),
+            window: None,
+            follow: false,
+            report: None,
+            tolerance: None,
+            missing: None,
+            anomalies: None,
+        };
+
+        while let Some(flag) = args.next() {
+            if flag == "--follow" {
+                options.follow = true;
+                continue;
+            }
+            if flag == "--anomalies" {
+                options.anomalies.get_or_insert_with(Thresholds::default);
+                continue;
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| format!("Missing value for {}", flag))?;
+            match flag.as_str() {
+                "--report" => {
+                    options.report = Some(match value.as_str() {
+                        "table" => Format::Table,
+                        "json" => Format::Json,
+                        _ => return Err(format!("Unknown report format {}", value)),
+                    });
+                }
+                "--tolerance" => {
+                    let tolerance = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|t| *t >= 0.0 && t.is_finite())
+                        .ok_or_else(|| format!("Invalid tolerance {}", value))?;
+                    options.tolerance = Some(tolerance);
+                }
+                "--missing" => {
+                    options.missing = Some(match value.as_str() {
+                        "reject" => Missing::Reject,
+                        "skip" => Missing::Skip,
+                        "hold" => Missing::Hold,
+                        _ => return Err(format!("Unknown handling of missing readings {}", value)),
+                    });
+                }
+                "--z-score" | "--jump" => {
+                    let limit = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|l| *l >= 0.0 && l.is_finite())
+                        .ok_or_else(|| format!("Invalid threshold {}", value))?;
+                    let thresholds = options.anomalies.get_or_insert_with(Thresholds::default);
+                    match flag.as_str() {
+                        "--z-score" => thresholds.z_score = Some(limit),
+                        _ => thresholds.jump = Some(limit),
+                    }
+                }
+                "--flatline" => {
+                    let length = value
+                        .parse()
+                        .ok()
+                        .filter(|l| *l >= 2)
+                        .ok_or_else(|| format!("Invalid flatline length {}", value))?;
+
+                    options
+                        .anomalies
+                        .get_or_insert_with(Thresholds::default)
+                        .flatline = Some(length);
+                }
+                "--window" => {
+                    let window = value
+                        .parse()
+                        .map_err(|_| format!("Invalid window size {}", value))?;
+                    options.window = Some(window);
+                }
+                _ => {
+                    options.source = InputSource::from_flag(&flag, value)
+                        .ok_or_else(|| format!("Unknown flag {}", flag))?;
+                }
+            }
+        }
+
+        if options.follow && options.report.is_some() {
+            return Err("--follow and --report cannot be combined".to_string());
+        }
+        if options.readings() && (options.follow || options.report.is_some()) {
+            return Err("--follow and --report only read unsigned depths".to_string());
+        }
+        if let Some(thresholds) = &mut options.anomalies {
+            if options.follow || options.report.is_some() || options.tolerance.is_some() {
+                return Err(
+                    "--anomalies cannot be combined with --follow, --report or --tolerance"
+                        .to_string(),
+                );
+            }
+            if let Some(window) = options.window {
+                thresholds.window = window;
+            }
+        }
+        Ok(options)
+    }
+
+    /// Whether the input holds readings rather than puzzle depths.
+    fn readings(&self) -> bool {
+        self.tolerance.is_some() || self.missing.is_some()
+    }
+}
 
 fn main() {
-    let input = read_to_string("input.txt").expect("Cannot read file input");
+    let options = Options::parse(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        process::exit(2)
+    });
+
+    if options.follow {
+        return follow(&options.source, options.window.unwrap_or(1));
+    }
+    if let Some(thresholds) = &options.anomalies {
+        return anomalies(&options, thresholds);
+    }
+    if options.readings() {
+        return readings(&options);
+    }
+    if options.report.is_none() && options.window.is_none() {
+        return common::run_on::<Day01>(&options.source);
+    }
+    let window = options.window.unwrap_or(1);
 
-    let input = input
-        .lines()
-        .map(|l| l.parse().expect("Cannot parse integer"))
-        .collect::<Vec<_>>();
+    let input = options.source.read().unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1)
+    });
+    let depths = Day01::parse(&input).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1)
+    });
 
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    let printed = match options.report {
+        Some(Format::Table) => DepthReport::new(&depths, window).map(|r| print!("{}", r.table())),
+        Some(Format::Json) => DepthReport::new(&depths, window).map(|r| println!("{}", r.json())),
+        None => day01::count_increases(&depths, window)
+            .map(|count| println!("Increases over windows of {}: {}", window, count)),
+    };
+    if let Err(e) = printed {
+        eprintln!("Day 01: {}", e);
+        process::exit(1)
+    }
 }
 
-fn part1(s: &[u64]) -> usize {
-    solve(s, 2)
+/// Prints the running count of increases over windows of `window` depths
+/// after each depth of `source`, in constant memory.
+fn follow(source: &InputSource, window: usize) {
+    let fail = |e: &dyn Display| -> ! {
+        eprintln!("{}", e);
+        process::exit(1)
+    };
+
+    let sweep = Sweep::new(window).unwrap_or_else(|e| fail(&format!("Day 01: {}", e)));
+    let mut lines = source.lines().unwrap_or_else(|e| fail(&e));
+
+    let followed = common::stream(&mut lines, day01::parse_depth, |depths| {
+        sweep
+            .running(depths)
+            .for_each(|count| println!("{}", count));
+        Ok(())
+    });
+    lines.finish().unwrap_or_else(|e| fail(&e));
+    followed.unwrap_or_else(|e| fail(&e));
 }
 
-fn part2(s: &[u64]) -> usize {
-    // a + b + c < b + c + d <=> a < d
-    solve(s, 4)
+/// Prints the increases of signed or fractional readings beyond the
+/// tolerance, over `--window` or as both parts when not set.
+fn readings(options: &Options) {
+    let fail = |e: &dyn Display| -> ! {
+        eprintln!("{}", e);
+        process::exit(1)
+    };
+
+    let input = options.source.read().unwrap_or_else(|e| fail(&e));
+    let readings = day01::parse_readings(&input, options.missing.unwrap_or(Missing::Reject))
+        .unwrap_or_else(|e| fail(&e));
+    let tolerance = options.tolerance.unwrap_or(0.0);
+    let count = |window| {
+        day01::count_increases_by(&readings, window, tolerance)
+            .unwrap_or_else(|e| fail(&format!("Day 01: {}", e)))
+    };
+
+    match options.window {
+        Some(window) => println!("Increases over windows of {}: {}", window, count(window)),
+        None => {
+            println!("Part 1: {}", count(1));
+            println!("Part 2: {}", count(3));
+        }
+    }
 }
 
-fn solve(s: &[u64], n: usize) -> usize {
-    s.windows(n).filter(|w| w[0] < w[n - 1]).count()
+/// Prints the index and reason of each suspicious reading, or that there is
+/// none.
+fn anomalies(options: &Options, thresholds: &Thresholds) {
+    let fail = |e: &dyn Display| -> ! {
+        eprintln!("{}", e);
+        process::exit(1)
+    };
+
+    let input = options.source.read().unwrap_or_else(|e| fail(&e));
+    let readings = day01::parse_readings(&input, options.missing.unwrap_or(Missing::Reject))
+        .unwrap_or_else(|e| fail(&e));
+    let anomalies =
+        day01::detect(&readings, thresholds).unwrap_or_else(|e| fail(&format!("Day 01: {}", e)));
+
+    if anomalies.is_empty() {
+        println!("No anomaly");
+    }
+    for anomaly in anomalies {
+        println!("{}: {}", anomaly.index, anomaly.reason);
+    }
 }
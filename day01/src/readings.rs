@@ -0,0 +1,190 @@
+use std::fmt;
+
+use common::{ParseError, Solution};
+
+use crate::{check_window, slide, Day01, WindowError};
+
+/// Sensor reading, kept as an integer when it has no fractional part so that
+/// large integers compare exactly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reading {
+    /// Reading such as `-12`.
+    Integer(i64),
+    /// Reading such as `3.75`, or an integer out of the range of `i64`.
+    Fraction(f64),
+}
+
+impl Reading {
+    /// Closest `f64`, for statistics.
+    pub fn as_f64(self) -> f64 {
+        match self {
+            Reading::Integer(r) => r as f64,
+            Reading::Fraction(r) => r,
+        }
+    }
+
+    /// Whether the reading is larger than `previous` by more than
+    /// `tolerance`, exactly when both are integers.
+    pub fn rises_over(self, previous: Reading, tolerance: f64) -> bool {
+        match (previous, self) {
+            (Reading::Integer(a), Reading::Integer(b)) if !tolerance.is_nan() => {
+                // An integer change is above the tolerance exactly when it is
+                // above its floor.
+                b as i128 - a as i128 > tolerance.floor() as i128
+            }
+            _ => self.as_f64() - previous.as_f64() > tolerance,
+        }
+    }
+
+    /// Whether both readings are equal, exactly when both are integers.
+    pub fn same(self, other: Reading) -> bool {
+        match (self, other) {
+            (Reading::Integer(a), Reading::Integer(b)) => a == b,
+            _ => self.as_f64() == other.as_f64(),
+        }
+    }
+}
+
+impl fmt::Display for Reading {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reading::Integer(r) => write!(f, "{}", r),
+            Reading::Fraction(r) => write!(f, "{}", r),
+        }
+    }
+}
+
+/// What to do with a missing reading: an empty line or `NaN`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Missing {
+    /// Fails to parse.
+    Reject,
+    /// Drops the reading, so that windows span the gap.
+    Skip,
+    /// Repeats the previous reading. Readings missing before the first one
+    /// are dropped.
+    Hold,
+}
+
+/// Reads one sensor reading per line, such as `-12` or `3.75`. Integers in
+/// the range of `i64` are read exactly, other readings as `f64`.
+pub fn parse_readings(input: &str, missing: Missing) -> Result<Vec<Reading>, ParseError> {
+    let mut readings = Vec::new();
+
+    for l in input.lines() {
+        let l = l.trim();
+        let reading = match (l.parse::<i64>(), l.parse::<f64>()) {
+            (Ok(r), _) => Some(Reading::Integer(r)),
+            (_, Ok(r)) if r.is_nan() => None,
+            (_, Ok(r)) if r.is_finite() => Some(Reading::Fraction(r)),
+            (_, Err(_)) if l.is_empty() => None,
+            _ => return Err(ParseError::at(Day01::DAY, input, l, "number")),
+        };
+
+        match (reading, missing) {
+            (Some(r), _) => readings.push(r),
+            (None, Missing::Reject) => {
+                return Err(ParseError::at(Day01::DAY, input, l, "number"));
+            }
+            (None, Missing::Skip) => {}
+            (None, Missing::Hold) => {
+                if let Some(&previous) = readings.last() {
+                    readings.push(previous);
+                }
+            }
+        }
+    }
+
+    Ok(readings)
+}
+
+/// Number of sums of `window` consecutive readings larger than the previous
+/// sum by more than `tolerance`, so that jitter within it counts as no change.
+/// A `NaN` reading or tolerance counts no increase.
+pub fn count_increases_by(
+    readings: &[Reading],
+    window: usize,
+    tolerance: f64,
+) -> Result<usize, WindowError> {
+    check_window(readings.len(), window)?;
+
+    // Consecutive sums differ by their last reading minus their first one.
+    Ok(slide(readings, window)
+        .filter(|(w, last)| last.rises_over(w[0], tolerance))
+        .count())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{count_increases, parse};
+
+    use Reading::{Fraction, Integer};
+
+    #[test]
+    fn parse_readings_test() {
+        let input = "-3\n2.5\n\n NaN\n7 \n";
+
+        assert_eq!(
+            parse_readings(input, Missing::Skip),
+            Ok(vec![Integer(-3), Fraction(2.5), Integer(7)])
+        );
+        assert_eq!(
+            parse_readings(input, Missing::Hold),
+            Ok(vec![
+                Integer(-3),
+                Fraction(2.5),
+                Fraction(2.5),
+                Fraction(2.5),
+                Integer(7)
+            ])
+        );
+        assert_eq!(parse_readings("\n1\n", Missing::Hold), Ok(vec![Integer(1)]));
+        assert_eq!(
+            parse_readings("9223372036854775807\n1e30\n", Missing::Reject),
+            Ok(vec![Integer(i64::MAX), Fraction(1e30)])
+        );
+
+        let error = parse_readings(input, Missing::Reject).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "");
+        let error =
+            parse_readings(input.replace("NaN", "inf").as_str(), Missing::Skip).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (4, 2, "inf")
+        );
+        let error = parse_readings("1\n2,5\n", Missing::Skip).unwrap_err();
+        assert_eq!((error.line, error.text.as_str()), (2, "2,5"));
+    }
+
+    #[test]
+    fn count_increases_by_test() {
+        let depths = parse(Day01::METADATA.example).unwrap();
+        let readings = depths
+            .iter()
+            .map(|&d| Integer(d as i64))
+            .collect::<Vec<_>>();
+
+        for window in 1..=10 {
+            assert_eq!(
+                count_increases_by(&readings, window, 0.0),
+                count_increases(&depths, window)
+            );
+        }
+
+        let noisy = [-1.0, -0.9, -1.2, 0.5, 0.45, f64::NAN, 3.0].map(Fraction);
+        assert_eq!(count_increases_by(&noisy, 1, 0.0), Ok(2));
+        assert_eq!(count_increases_by(&noisy, 1, 0.2), Ok(1));
+        assert_eq!(count_increases_by(&noisy, 1, f64::NAN), Ok(0));
+        assert_eq!(count_increases_by(&noisy, 0, 0.2), Err(WindowError::Empty));
+
+        // Distinct as integers, but the same f64.
+        let large = [Integer(i64::MAX - 1), Integer(i64::MAX), Integer(i64::MIN)];
+        assert_eq!(count_increases_by(&large, 1, 0.0), Ok(1));
+        assert_eq!(count_increases_by(&large, 1, 0.5), Ok(1));
+        assert_eq!(count_increases_by(&large, 1, 1.0), Ok(0));
+        assert!(!Integer(i64::MAX - 1).same(Integer(i64::MAX)));
+        assert!(Integer(2).same(Fraction(2.0)));
+    }
+}
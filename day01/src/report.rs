@@ -0,0 +1,314 @@
+use std::{cmp::Ordering, fmt::Write as _};
+
+use crate::{check_window, slide, WindowError};
+
+/// Every change between the sums of consecutive windows of depths, for a
+/// closer look than the count of increases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepthReport {
+    /// Depths in a window.
+    pub window: usize,
+    /// Changes in the order of the windows.
+    pub changes: Vec<Change>,
+}
+
+/// Change from the sum of a window of depths to the sum of the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    /// Index of the later window, that is of its first depth.
+    pub index: usize,
+    /// Sum of the previous window, wide enough for any window of depths.
+    pub from: u128,
+    /// Sum of the window at `index`.
+    pub to: u128,
+}
+
+/// Whether the sea floor gets deeper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Deeper sum.
+    Increase,
+    /// Shallower sum.
+    Decrease,
+    /// Same sum.
+    Unchanged,
+}
+
+/// Consecutive increases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    /// Index of the window of the first increase.
+    pub start: usize,
+    /// Number of increases.
+    pub len: usize,
+}
+
+impl Change {
+    /// Whether the later sum is deeper.
+    pub fn direction(&self) -> Direction {
+        match self.to.cmp(&self.from) {
+            Ordering::Greater => Direction::Increase,
+            Ordering::Less => Direction::Decrease,
+            Ordering::Equal => Direction::Unchanged,
+        }
+    }
+
+    /// Difference between the sums, whatever the direction.
+    pub fn magnitude(&self) -> u128 {
+        self.to.abs_diff(self.from)
+    }
+
+    /// Magnitude with the sign of the direction, e.g. `-12`.
+    fn signed(&self) -> String {
+        match self.direction() {
+            Direction::Increase => format!("+{}", self.magnitude()),
+            Direction::Decrease => format!("-{}", self.magnitude()),
+            Direction::Unchanged => "0".to_string(),
+        }
+    }
+
+    fn json(&self) -> String {
+        format!(
+            "{{\"index\":{},\"from\":{},\"to\":{},\"direction\":\"{}\",\"magnitude\":{}}}",
+            self.index,
+            self.from,
+            self.to,
+            match self.direction() {
+                Direction::Increase => "increase",
+                Direction::Decrease => "decrease",
+                Direction::Unchanged => "unchanged",
+            },
+            self.magnitude()
+        )
+    }
+}
+
+impl DepthReport {
+    /// Report on the sums of `window` consecutive depths, with the same
+    /// window sizes as [`crate::count_increases`].
+    pub fn new(depths: &[u64], window: usize) -> Result<DepthReport, WindowError> {
+        check_window(depths.len(), window)?;
+
+        let mut sum = depths[..window].iter().map(|&d| d as u128).sum::<u128>();
+        let changes = slide(depths, window)
+            .enumerate()
+            .map(|(i, (w, &last))| {
+                let from = sum;
+                sum = sum - w[0] as u128 + last as u128;
+
+                Change {
+                    index: i + 1,
+                    from,
+                    to: sum,
+                }
+            })
+            .collect();
+
+        Ok(DepthReport { window, changes })
+    }
+
+    /// Number of changes going in `direction`.
+    pub fn count(&self, direction: Direction) -> usize {
+        self.changes
+            .iter()
+            .filter(|c| c.direction() == direction)
+            .count()
+    }
+
+    /// Longest run of consecutive increases, the first one on ties.
+    pub fn longest_increase(&self) -> Option<Run> {
+        let mut longest: Option<Run> = None;
+        let mut current: Option<Run> = None;
+
+        for change in &self.changes {
+            current = match (change.direction(), current) {
+                (Direction::Increase, Some(run)) => Some(Run {
+                    len: run.len + 1,
+                    ..run
+                }),
+                (Direction::Increase, None) => Some(Run {
+                    start: change.index,
+                    len: 1,
+                }),
+                _ => None,
+            };
+            if let Some(run) = current {
+                if longest.is_none_or(|l| l.len < run.len) {
+                    longest = Some(run);
+                }
+            }
+        }
+        longest
+    }
+
+    /// Largest increase, the first one on ties.
+    pub fn max_rise(&self) -> Option<Change> {
+        self.largest(Direction::Increase)
+    }
+
+    /// Largest decrease, the first one on ties.
+    pub fn max_fall(&self) -> Option<Change> {
+        self.largest(Direction::Decrease)
+    }
+
+    fn largest(&self, direction: Direction) -> Option<Change> {
+        self.changes
+            .iter()
+            .filter(|c| c.direction() == direction)
+            .copied()
+            .reduce(|a, b| if a.magnitude() < b.magnitude() { b } else { a })
+    }
+
+    /// One line per change followed by the totals, for people.
+    pub fn table(&self) -> String {
+        let mut s = format!(
+            "{:>8} {:>12} {:>12} {:>10}\n",
+            "Window", "From", "To", "Change"
+        );
+        for c in &self.changes {
+            let _ = writeln!(
+                s,
+                "{:>8} {:>12} {:>12} {:>10}",
+                c.index,
+                c.from,
+                c.to,
+                c.signed()
+            );
+        }
+
+        let _ = writeln!(s, "\nDepths per window: {}", self.window);
+        let _ = writeln!(s, "Increases: {}", self.count(Direction::Increase));
+        let _ = writeln!(s, "Decreases: {}", self.count(Direction::Decrease));
+        let _ = writeln!(s, "Unchanged: {}", self.count(Direction::Unchanged));
+        let _ = match self.longest_increase() {
+            Some(run) => writeln!(
+                s,
+                "Longest run of increases: {} from window {}",
+                run.len, run.start
+            ),
+            None => writeln!(s, "Longest run of increases: none"),
+        };
+        for (label, change) in [("rise", self.max_rise()), ("fall", self.max_fall())] {
+            let _ = match change {
+                Some(c) => writeln!(s, "Largest {}: {} at window {}", label, c.signed(), c.index),
+                None => writeln!(s, "Largest {}: none", label),
+            };
+        }
+        s
+    }
+
+    /// Totals and changes as a JSON object, for other tools.
+    pub fn json(&self) -> String {
+        let change = |c: Option<Change>| c.map_or("null".to_string(), |c| c.json());
+        let run = self.longest_increase().map_or("null".to_string(), |r| {
+            format!("{{\"start\":{},\"len\":{}}}", r.start, r.len)
+        });
+        let changes = self.changes.iter().map(Change::json).collect::<Vec<_>>();
+
+        format!(
+            "{{\"window\":{},\"increases\":{},\"decreases\":{},\"unchanged\":{},\"longest_increase\":{},\"max_rise\":{},\"max_fall\":{},\"changes\":[{}]}}",
+            self.window,
+            self.count(Direction::Increase),
+            self.count(Direction::Decrease),
+            self.count(Direction::Unchanged),
+            run,
+            change(self.max_rise()),
+            change(self.max_fall()),
+            changes.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::Solution;
+
+    use super::*;
+    use crate::{count_increases, parse, Day01};
+
+    #[test]
+    fn report_test() {
+        let depths = parse(Day01::METADATA.example).unwrap();
+        let report = DepthReport::new(&depths, 3).unwrap();
+
+        let sums = report.changes.iter().map(|c| c.to).collect::<Vec<_>>();
+        assert_eq!(sums, [618, 618, 617, 647, 716, 769, 792]);
+        assert_eq!(report.changes[0].from, 607);
+        assert_eq!(report.count(Direction::Increase), 5);
+        assert_eq!(report.count(Direction::Decrease), 1);
+        assert_eq!(report.count(Direction::Unchanged), 1);
+        assert_eq!(report.longest_increase(), Some(Run { start: 4, len: 4 }));
+        assert_eq!(
+            report.max_rise().map(|c| (c.index, c.magnitude())),
+            Some((5, 69))
+        );
+        assert_eq!(
+            report.max_fall().map(|c| (c.index, c.magnitude())),
+            Some((3, 1))
+        );
+        assert_eq!(
+            DepthReport::new(&depths, 11),
+            Err(WindowError::TooLarge {
+                window: 11,
+                depths: 10
+            })
+        );
+
+        for window in 1..=10 {
+            let report = DepthReport::new(&depths, window).unwrap();
+            assert_eq!(
+                Ok(report.count(Direction::Increase)),
+                count_increases(&depths, window)
+            );
+        }
+    }
+
+    #[test]
+    fn large_depths_test() {
+        let max = u64::MAX as u128;
+        let report = DepthReport::new(&[u64::MAX, 1, 2, u64::MAX], 2).unwrap();
+
+        let sums = report
+            .changes
+            .iter()
+            .map(|c| (c.from, c.to))
+            .collect::<Vec<_>>();
+        assert_eq!(sums, [(max + 1, 3), (3, max + 2)]);
+        assert_eq!(report.max_rise().unwrap().magnitude(), max - 1);
+        assert_eq!(report.max_fall().unwrap().magnitude(), max - 2);
+        assert_eq!(
+            Ok(report.count(Direction::Increase)),
+            count_increases(&[u64::MAX, 1, 2, u64::MAX], 2)
+        );
+    }
+
+    #[test]
+    fn print_test() {
+        let report = DepthReport::new(&[3, 5, 5, 1], 1).unwrap();
+
+        assert_eq!(
+            report.table(),
+            "  Window         From           To     Change\n\
+            \x20      1            3            5         +2\n\
+            \x20      2            5            5          0\n\
+            \x20      3            5            1         -4\n\
+            \n\
+            Depths per window: 1\n\
+            Increases: 1\n\
+            Decreases: 1\n\
+            Unchanged: 1\n\
+            Longest run of increases: 1 from window 1\n\
+            Largest rise: +2 at window 1\n\
+            Largest fall: -4 at window 3\n"
+        );
+        assert_eq!(
+            DepthReport::new(&[4], 1).unwrap().json(),
+            "{\"window\":1,\"increases\":0,\"decreases\":0,\"unchanged\":0,\"longest_increase\":null,\"max_rise\":null,\"max_fall\":null,\"changes\":[]}"
+        );
+        assert!(report.json().ends_with(
+            "\"changes\":[{\"index\":1,\"from\":3,\"to\":5,\"direction\":\"increase\",\"magnitude\":2},\
+            {\"index\":2,\"from\":5,\"to\":5,\"direction\":\"unchanged\",\"magnitude\":0},\
+            {\"index\":3,\"from\":5,\"to\":1,\"direction\":\"decrease\",\"magnitude\":4}]}"
+        ));
+    }
+}
@@ -0,0 +1,32 @@
+use common::{Generator, Rng};
+
+use crate::Day02;
+
+const MAX_AIM: usize = 1000;
+
+impl Generator for Day02 {
+    /// `size` commands, never going up above the surface and keeping the aim
+    /// bounded so that the answers fit in a `u64`.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut aim = 0;
+
+        (0..size)
+            .map(|_| {
+                let unit = rng.range(1..10);
+                let direction = match rng.range(0..3) {
+                    0 => "forward",
+                    _ if unit <= aim && (MAX_AIM < aim + unit || rng.ratio(1, 2)) => {
+                        aim -= unit;
+                        "up"
+                    }
+                    _ if aim + unit <= MAX_AIM => {
+                        aim += unit;
+                        "down"
+                    }
+                    _ => "forward",
+                };
+                format!("{} {}\n", direction, unit)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,211 @@
+//! Day 2: Dive! Follows the planned course of the submarine.
+
+#![warn(missing_docs)]
+
+mod generator;
+
+use common::{Metadata, ParseError, PuzzleError, Solution, SolveError};
+
+/// Solver of day 2, for the runner and generic code over [`Solution`].
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    const METADATA: Metadata = Metadata {
+        title: "Dive!",
+        input: "one command per line: `forward`, `down` or `up`, then an unsigned integer",
+        example: include_str!("../example.txt"),
+        answers: ["150", "900"],
+    };
+
+    type Input<'a> = Vec<(&'a str, u64)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        part2(input)
+    }
+
+    fn stream1(lines: &mut dyn Iterator<Item = String>) -> Option<Result<u64, PuzzleError>> {
+        Some(common::stream(lines, parse_command, |c| position(c)))
+    }
+
+    fn stream2(lines: &mut dyn Iterator<Item = String>) -> Option<Result<u64, PuzzleError>> {
+        Some(common::stream(lines, parse_command, |c| aimed_position(c)))
+    }
+}
+
+/// Reads one command per line: `forward`, `down` or `up`, then a unit.
+pub fn parse(input: &str) -> Result<Vec<(&str, u64)>, ParseError> {
+    input.lines().map(|l| parse_line(input, l)).collect()
+}
+
+/// Every line [`parse`] would stop at.
+pub fn check(input: &str) -> Vec<ParseError> {
+    input
+        .lines()
+        .filter_map(|l| parse_line(input, l).err())
+        .collect()
+}
+
+fn parse_line(input: &str, l: &str) -> Result<(&'static str, u64), ParseError> {
+    let error = |token: &str, expected| ParseError::at(Day02::DAY, input, token, expected);
+
+    let mut it = l.split_ascii_whitespace();
+    let direction = it.next().ok_or_else(|| error(l, "direction"))?;
+    let direction = ["forward", "down", "up"]
+        .into_iter()
+        .find(|&d| d == direction)
+        .ok_or_else(|| error(direction, "forward, down or up"))?;
+    let unit = it.next().ok_or_else(|| error(&l[l.len()..], "unit"))?;
+    let unit = unit
+        .parse::<u64>()
+        .map_err(|_| error(unit, "unit as integer"))?;
+
+    match it.next() {
+        Some(extra) => Err(error(extra, "end of line")),
+        None => Ok((direction, unit)),
+    }
+}
+
+fn parse_command(l: String) -> Result<(&'static str, u64), ParseError> {
+    parse_line(&l, &l)
+}
+
+/// Final horizontal position times depth, where `down` and `up` change the
+/// depth directly.
+pub fn part1(input: &[(&str, u64)]) -> Result<u64, SolveError> {
+    position(input.iter().copied())
+}
+
+fn position<'a>(mut commands: impl Iterator<Item = (&'a str, u64)>) -> Result<u64, SolveError> {
+    let overflow = || error(1, "position out of range");
+
+    let (horizontal, depth) = commands.try_fold(
+        (0u64, 0u64),
+        |(horizontal, depth), (direction, unit)| match direction {
+            "forward" => Ok((horizontal.checked_add(unit).ok_or_else(overflow)?, depth)),
+            "down" => Ok((horizontal, depth.checked_add(unit).ok_or_else(overflow)?)),
+            "up" => Ok((horizontal, depth.checked_sub(unit).ok_or_else(|| above(1))?)),
+            d => Err(error(1, format!("unknown direction {}", d))),
+        },
+    )?;
+
+    horizontal.checked_mul(depth).ok_or_else(overflow)
+}
+
+/// Final horizontal position times depth, where `down` and `up` change the
+/// aim and `forward` dives along it.
+pub fn part2(input: &[(&str, u64)]) -> Result<u64, SolveError> {
+    aimed_position(input.iter().copied())
+}
+
+fn aimed_position<'a>(
+    mut commands: impl Iterator<Item = (&'a str, u64)>,
+) -> Result<u64, SolveError> {
+    let overflow = || error(2, "position out of range");
+
+    let (horizontal, depth, _aim) = commands.try_fold(
+        (0u64, 0u64, 0u64),
+        |(horizontal, depth, aim), (direction, unit)| match direction {
+            "forward" => Ok((
+                horizontal.checked_add(unit).ok_or_else(overflow)?,
+                aim.checked_mul(unit)
+                    .and_then(|dive| depth.checked_add(dive))
+                    .ok_or_else(overflow)?,
+                aim,
+            )),
+            "down" => Ok((
+                horizontal,
+                depth,
+                aim.checked_add(unit).ok_or_else(overflow)?,
+            )),
+            "up" => Ok((
+                horizontal,
+                depth,
+                aim.checked_sub(unit).ok_or_else(|| above(2))?,
+            )),
+            d => Err(error(2, format!("unknown direction {}", d))),
+        },
+    )?;
+
+    horizontal.checked_mul(depth).ok_or_else(overflow)
+}
+
+fn error(part: u8, reason: impl Into<String>) -> SolveError {
+    SolveError::new(Day02::DAY, part, reason)
+}
+
+fn above(part: u8) -> SolveError {
+    error(part, "submarine goes above the surface")
+}
+
+#[cfg(test)]
+mod test {
+    use common::{Generator, Rng};
+
+    use super::*;
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("forward 5\ndown 5\nbackward 8\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.text, "backward");
+
+        let error = parse("forward 5\nup x3").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.text, "x3");
+    }
+
+    #[test]
+    fn check_test() {
+        let errors = check("forward 5\nbackward 8\ndown 5\nup x3\nup 3 4\n");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column, e.expected.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (2, 1, "forward, down or up"),
+                (4, 4, "unit as integer"),
+                (5, 6, "end of line"),
+            ]
+        );
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day02::generate(&mut Rng::new(seed), 100);
+            let input = Day02::parse(&input).unwrap();
+            Day02::part1(&input).unwrap();
+            Day02::part2(&input).unwrap();
+        }
+    }
+
+    #[test]
+    fn solve_error_test() {
+        let error = part1(&parse("down 2\nup 3").unwrap()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Day 02, part 1: submarine goes above the surface"
+        );
+
+        let input = parse("down 9223372036854775808\nforward 2").unwrap();
+        assert_eq!(part1(&input).unwrap_err().reason, "position out of range");
+        assert_eq!(part2(&input).unwrap_err().reason, "position out of range");
+    }
+}
@@ -0,0 +1,25 @@
+use common::{Generator, Rng};
+
+use crate::Day03;
+
+const LOW_BITS: usize = 4;
+
+impl Generator for Day03 {
+    /// At least `size` distinct numbers. Their high bits take every value of
+    /// the smallest power of two above `size`, so that both ratings narrow
+    /// down to a single number; their low bits are random.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let high_bits = (usize::BITS - size.saturating_sub(1).leading_zeros()).min(28) as usize;
+        let len = high_bits + LOW_BITS;
+
+        let mut numbers = (0..1usize << high_bits)
+            .map(|high| high << LOW_BITS | rng.range(0..1 << LOW_BITS))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut numbers);
+
+        numbers
+            .into_iter()
+            .map(|n| format!("{:0len$b}\n", n, len = len))
+            .collect()
+    }
+}
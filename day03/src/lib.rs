@@ -0,0 +1,234 @@
+//! Day 3: Binary Diagnostic. Reads rates out of columns of bits.
+
+#![warn(missing_docs)]
+
+mod generator;
+
+use common::{Metadata, ParseError, Solution, SolveError};
+
+/// Solver of day 3, for the runner and generic code over [`Solution`].
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    const METADATA: Metadata = Metadata {
+        title: "Binary Diagnostic",
+        input: "one binary number per line, all of the same length, at most 32 digits",
+        example: include_str!("../example.txt"),
+        answers: ["198", "230"],
+    };
+
+    type Input<'a> = (usize, Vec<u32>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input.0, &input.1))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        part2(input.0, input.1.clone())
+    }
+}
+
+/// Reads one binary number per line, all of the same length. Returns that
+/// length and the numbers.
+pub fn parse(input: &str) -> Result<(usize, Vec<u32>), ParseError> {
+    let len = parse_len(input)?;
+    let numbers = input
+        .lines()
+        .map(|l| parse_line(input, l, len))
+        .collect::<Result<_, _>>()?;
+
+    Ok((len, numbers))
+}
+
+/// Every line [`parse`] would stop at, all checked against the length of the
+/// first one.
+pub fn check(input: &str) -> Vec<ParseError> {
+    match parse_len(input) {
+        Ok(len) => input
+            .lines()
+            .filter_map(|l| parse_line(input, l, len).err())
+            .collect(),
+        Err(e) => vec![e],
+    }
+}
+
+/// Length of the first number, which all others must share.
+fn parse_len(input: &str) -> Result<usize, ParseError> {
+    let error = |token: &str, expected: &str| ParseError::at(Day03::DAY, input, token, expected);
+
+    let len = input
+        .lines()
+        .next()
+        .filter(|l| !l.is_empty())
+        .ok_or_else(|| error(&input[..0], "binary number"))?
+        .len();
+    if 32 < len {
+        return Err(error(&input[..len], "at most 32 binary digits"));
+    }
+    Ok(len)
+}
+
+fn parse_line(input: &str, l: &str, len: usize) -> Result<u32, ParseError> {
+    let error = |token: &str, expected: &str| ParseError::at(Day03::DAY, input, token, expected);
+
+    if let Some((i, c)) = l.char_indices().find(|&(_, c)| c != '0' && c != '1') {
+        return Err(error(&l[i..i + c.len_utf8()], "binary digit"));
+    }
+    if l.len() != len {
+        return Err(error(l, &format!("{} binary digits", len)));
+    }
+    u32::from_str_radix(l, 2).map_err(|_| error(l, "binary number"))
+}
+
+/// Power consumption: gamma rate, made of the most common bits, times epsilon
+/// rate, made of the least common ones.
+pub fn part1(number_len: usize, numbers: &[u32]) -> u64 {
+    let most_common_bits = (0..number_len)
+        .rev()
+        .map(|position| most_significant(numbers, position))
+        .fold(0, |acc, most_significant_bit| {
+            acc << 1 | most_significant_bit
+        });
+
+    let least_significant_bits = (u32::MAX >> (32 - number_len)) ^ most_common_bits;
+
+    u64::from(least_significant_bits) * u64::from(most_common_bits)
+}
+
+/// Life support rating: oxygen generator rating times CO2 scrubber rating.
+pub fn part2(number_len: usize, numbers: Vec<u32>) -> Result<u64, SolveError> {
+    let rating = |numbers, f, name| {
+        life_support_tracking(number_len, numbers, f)
+            .map(u64::from)
+            .ok_or_else(|| SolveError::new(Day03::DAY, 2, format!("no single {} rating", name)))
+    };
+
+    let oxygen_generator_rating = rating(numbers.clone(), &most_significant, "oxygen generator")?;
+    let co2_scrubber_rating = rating(numbers, &least_significant, "CO2 scrubber")?;
+    Ok(oxygen_generator_rating * co2_scrubber_rating)
+}
+
+/// Keeps the numbers matching the bit criteria of `f`, column after column,
+/// until a single one is left. None when the columns run out before, e.g.
+/// because of duplicates.
+fn life_support_tracking(
+    number_len: usize,
+    mut numbers: Vec<u32>,
+    f: &dyn Fn(&[u32], usize) -> u32,
+) -> Option<u32> {
+    for column in (0..number_len).rev() {
+        if numbers.len() <= 1 {
+            break;
+        }
+
+        let bit_criteria = f(&numbers, column);
+
+        numbers.retain(|v| ((v >> column) & 1) == bit_criteria);
+    }
+
+    match numbers[..] {
+        [rating] => Some(rating),
+        _ => None,
+    }
+}
+
+fn most_significant(numbers: &[u32], position: usize) -> u32 {
+    let column_mask = 1 << position;
+    let one_count = numbers.iter().filter(|&n| column_mask & n != 0).count();
+
+    if 2 * one_count >= numbers.len() {
+        1
+    } else {
+        0
+    }
+}
+
+fn least_significant(numbers: &[u32], position: usize) -> u32 {
+    if most_significant(numbers, position) == 1 {
+        0
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use common::{Generator, Rng};
+
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let (len, numbers) = parse(Day03::METADATA.example).unwrap();
+        assert_eq!(part1(len, &numbers), 198)
+    }
+
+    #[test]
+    fn part2_test() {
+        let (len, numbers) = parse(Day03::METADATA.example).unwrap();
+        assert_eq!(part2(len, numbers), Ok(230))
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("00100\n11120\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.text, "2");
+
+        let error = parse("00100\n1111\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "5 binary digits");
+    }
+
+    #[test]
+    fn check_test() {
+        let errors = check("0101\n011\n0a01\n1100\n10101\n");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column, e.expected.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (2, 1, "4 binary digits"),
+                (3, 2, "binary digit"),
+                (5, 1, "4 binary digits"),
+            ]
+        );
+        assert_eq!(check("\n0101\n")[0].expected, "binary number");
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day03::generate(&mut Rng::new(seed), 100);
+            let input = Day03::parse(&input).unwrap();
+            Day03::part1(&input).unwrap();
+            Day03::part2(&input).unwrap();
+        }
+    }
+
+    #[test]
+    fn solve_error_test() {
+        let (len, numbers) = parse("101\n101\n011").unwrap();
+        assert_eq!(
+            part2(len, numbers).unwrap_err().to_string(),
+            "Day 03, part 2: no single oxygen generator rating"
+        );
+
+        let (len, numbers) = parse(&format!("{:032b}", 1)).unwrap();
+        assert_eq!(part1(len, &numbers), 0xffff_fffe);
+    }
+}
@@ -0,0 +1,6 @@
+//! Example of the puzzle statement, from the metadata of the day.
+
+#[test]
+fn example_test() {
+    common::test_example::<day03::Day03>();
+}
@@ -0,0 +1,31 @@
+use common::{Generator, Rng};
+
+use crate::Day04;
+
+impl Generator for Day04 {
+    /// `size` boards of distinct numbers below 100, at least one, and draws
+    /// of every number below 100 so that all boards eventually win.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut numbers = (0..100).collect::<Vec<_>>();
+
+        rng.shuffle(&mut numbers);
+        let mut input = numbers
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        input.push('\n');
+
+        for _ in 0..size.max(1) {
+            rng.shuffle(&mut numbers);
+            input.push('\n');
+            for row in numbers[..25].chunks(5) {
+                let row = row.iter().map(|n| format!("{:2}", n)).collect::<Vec<_>>();
+                input.push_str(&row.join(" "));
+                input.push('\n');
+            }
+        }
+
+        input
+    }
+}
@@ -0,0 +1,343 @@
+//! Day 4: Giant Squid. Plays bingo against a giant squid.
+
+#![warn(missing_docs)]
+
+mod generator;
+
+use std::collections::{HashMap, HashSet};
+
+use common::{Metadata, ParseError, Solution, SolveError};
+
+/// Solver of day 4, for the runner and generic code over [`Solution`].
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    const METADATA: Metadata = Metadata {
+        title: "Giant Squid",
+        input: "comma separated draws, then 5x5 boards of integers, each after an empty line",
+        example: include_str!("../example.txt"),
+        answers: ["4512", "1924"],
+    };
+
+    type Input<'a> = (Vec<u32>, Vec<Bingo>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        part1(&input.0, input.1.clone())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        part2(&input.0, input.1.clone())
+    }
+}
+
+/// Reads the comma separated draws, then the 5x5 boards separated by empty
+/// lines.
+pub fn parse(input: &str) -> Result<(Vec<u32>, Vec<Bingo>), ParseError> {
+    let error = |token: &str, expected: &str| ParseError::at(Day04::DAY, input, token, expected);
+
+    let mut it = input.lines();
+
+    let draws = it
+        .next()
+        .ok_or_else(|| error(input, "draw numbers"))?
+        .split(',')
+        .map(|n| n.parse().map_err(|_| error(n, "draw number")))
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    let grids = it
+        .try_fold(Vec::new(), |mut acc: Vec<Vec<&str>>, l| {
+            if l.is_empty() {
+                acc.push(Vec::new());
+            } else {
+                acc.last_mut()
+                    .ok_or_else(|| error(l, "empty line before grid"))?
+                    .push(l);
+            }
+            Ok(acc)
+        })?
+        .into_iter()
+        .filter(|rows| !rows.is_empty())
+        .map(|rows| {
+            if rows.len() != 5 {
+                return Err(error(rows[0], "grid of 5 rows"));
+            }
+
+            let numbers = rows
+                .iter()
+                .map(|l| parse_row(input, l))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Bingo::new(numbers.concat()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if grids.is_empty() {
+        return Err(error(&input[input.len()..], "bingo board"));
+    }
+
+    Ok((draws, grids))
+}
+
+fn parse_row(input: &str, l: &str) -> Result<Vec<u32>, ParseError> {
+    let error = |token: &str, expected: &str| ParseError::at(Day04::DAY, input, token, expected);
+
+    let row = l
+        .split_whitespace()
+        .map(|n| n.parse::<u32>().map_err(|_| error(n, "grid integer")))
+        .collect::<Result<Vec<_>, _>>()?;
+    if row.len() != 5 {
+        return Err(error(l, "row of 5 integers"));
+    }
+    Ok(row)
+}
+
+/// Every problem [`parse`] would stop at, one per draw, row or board, and
+/// numbers repeated on a board, which could never all be marked.
+pub fn check(input: &str) -> Vec<ParseError> {
+    let error = |token: &str, expected: &str| ParseError::at(Day04::DAY, input, token, expected);
+
+    let mut it = input.lines();
+    let mut errors: Vec<_> = match it.next() {
+        Some(draws) => draws
+            .split(',')
+            .filter(|n| n.parse::<u32>().is_err())
+            .map(|n| error(n, "draw number"))
+            .collect(),
+        None => return vec![error(input, "draw numbers")],
+    };
+
+    let mut boards: Vec<Vec<&str>> = Vec::new();
+    for l in it {
+        if l.is_empty() {
+            boards.push(Vec::new());
+        } else if let Some(rows) = boards.last_mut() {
+            rows.push(l);
+        } else {
+            errors.push(error(l, "empty line before grid"));
+        }
+    }
+    boards.retain(|rows| !rows.is_empty());
+
+    for rows in &boards {
+        if rows.len() != 5 {
+            errors.push(error(rows[0], "grid of 5 rows"));
+        }
+
+        let mut seen = HashSet::new();
+        for l in rows {
+            match parse_row(input, l) {
+                Ok(row) => {
+                    for (n, token) in row.into_iter().zip(l.split_whitespace()) {
+                        if !seen.insert(n) {
+                            errors.push(error(token, "number not yet on the board"));
+                        }
+                    }
+                }
+                Err(e) => errors.push(e),
+            }
+        }
+    }
+
+    if boards.is_empty() {
+        errors.push(error(&input[input.len()..], "bingo board"));
+    }
+
+    errors
+}
+
+/// Bingo board of 5x5 numbers, which remembers the numbers marked so far.
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    board: u32,
+    numbers: HashMap<u32, u32>,
+}
+
+impl Bingo {
+    /// Board of the 25 `numbers`, row after row, none marked. Private, as
+    /// only [`parse`] makes sure that there are 25 of them.
+    fn new(numbers: Vec<u32>) -> Bingo {
+        debug_assert_eq!(numbers.len(), 25);
+
+        Bingo {
+            board: 0,
+            numbers: numbers
+                .into_iter()
+                .enumerate()
+                .map(|(i, n)| (n, 1 << i))
+                .collect(),
+        }
+    }
+
+    /// Sum of the numbers not marked yet.
+    pub fn sum_unmarked_numbers(&self) -> u64 {
+        self.numbers
+            .iter()
+            .filter_map(|(number, &position)| {
+                if !self.is_marked(position) {
+                    Some(u64::from(*number))
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+
+    /// Whether a full row or a full column is marked.
+    pub fn is_winner(&self) -> bool {
+        [
+            0b00000001111100000000000000000000,
+            0b00000000000011111000000000000000,
+            0b00000000000000000111110000000000,
+            0b00000000000000000000001111100000,
+            0b00000000000000000000000000011111,
+            0b00000001000010000100001000010000,
+            0b00000000100001000010000100001000,
+            0b00000000010000100001000010000100,
+            0b00000000001000010000100001000010,
+            0b00000000000100001000010000100001,
+        ]
+        .into_iter()
+        .any(|position| self.is_marked(position))
+    }
+
+    fn is_marked(&self, position: u32) -> bool {
+        self.board & position == position
+    }
+
+    /// Marks `number`, if it is on the board.
+    pub fn mark(&mut self, number: u32) {
+        if let Some(&position) = self.numbers.get(&number) {
+            self.board |= position;
+        }
+    }
+}
+
+/// Score of the first board to win: its unmarked numbers summed, times the
+/// last draw.
+pub fn part1(draws: &[u32], mut grids: Vec<Bingo>) -> Result<u64, SolveError> {
+    for d in draws {
+        for g in &mut grids {
+            g.mark(*d);
+            if g.is_winner() {
+                return Ok(g.sum_unmarked_numbers() * u64::from(*d));
+            }
+        }
+    }
+    Err(SolveError::new(Day04::DAY, 1, "no board wins"))
+}
+
+/// Score of the last board to win.
+pub fn part2(draws: &[u32], mut grids: Vec<Bingo>) -> Result<u64, SolveError> {
+    let mut bingo_players = grids.len();
+
+    for d in draws {
+        for g in &mut grids {
+            if !g.is_winner() {
+                g.mark(*d);
+                if g.is_winner() {
+                    bingo_players -= 1;
+                    if bingo_players == 0 {
+                        return Ok(g.sum_unmarked_numbers() * u64::from(*d));
+                    }
+                }
+            }
+        }
+    }
+    Err(SolveError::new(Day04::DAY, 2, "some boards never win"))
+}
+
+#[cfg(test)]
+mod test {
+
+    use common::{Generator, Rng};
+
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let (draws, grids) = parse(Day04::METADATA.example).unwrap();
+
+        assert_eq!(part1(&draws, grids), Ok(4512))
+    }
+
+    #[test]
+    fn part2_test() {
+        let (draws, grids) = parse(Day04::METADATA.example).unwrap();
+
+        assert_eq!(part2(&draws, grids), Ok(1924))
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("7,4,x9\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        assert_eq!(error.text, "x9");
+
+        let error =
+            parse("7,4\n\n1 2 3 4 5\n1 2 3 4\n1 2 3 4 5\n1 2 3 4 5\n1 2 3 4 5\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.expected, "row of 5 integers");
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day04::generate(&mut Rng::new(seed), 20);
+            let input = Day04::parse(&input).unwrap();
+            Day04::part1(&input).unwrap();
+            Day04::part2(&input).unwrap();
+        }
+
+        let input = Day04::generate(&mut Rng::new(0), 0);
+        assert_eq!(Day04::parse(&input).unwrap().1.len(), 1);
+    }
+
+    #[test]
+    fn solve_error_test() {
+        let board = "1 2 3 4 5\n6 7 8 9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25\n";
+        let (draws, grids) = parse(&format!("1,2,3,4,5\n\n{}\n{}", board, board)).unwrap();
+        assert_eq!(part1(&draws, grids.clone()), Ok(310 * 5));
+        assert_eq!(part2(&draws, grids), Ok(310 * 5));
+
+        let (draws, grids) = parse(&format!("1,2,3,4\n\n{}", board)).unwrap();
+        assert_eq!(
+            part1(&draws, grids.clone()).unwrap_err().to_string(),
+            "Day 04, part 1: no board wins"
+        );
+        assert!(part2(&draws, grids).is_err());
+
+        let error = parse("1,2,3\n").unwrap_err();
+        assert_eq!(error.expected, "bingo board");
+    }
+
+    #[test]
+    fn check_test() {
+        let errors = check("7,x,9,y\n1 2 3 4 5\n\n1 2 3 4 5\n6 7 8 9 1\n1 2\n");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column, e.expected.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (1, 3, "draw number"),
+                (1, 7, "draw number"),
+                (2, 1, "empty line before grid"),
+                (4, 1, "grid of 5 rows"),
+                (5, 9, "number not yet on the board"),
+                (6, 1, "row of 5 integers"),
+            ]
+        );
+    }
+}
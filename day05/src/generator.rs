@@ -0,0 +1,32 @@
+use common::{Generator, Rng};
+
+use crate::Day05;
+
+const SIDE: usize = 1000;
+
+impl Generator for Day05 {
+    /// `size` horizontal, vertical or diagonal lines inside a 1000x1000 area.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let (x0, y0) = (rng.range(0..SIDE), rng.range(0..SIDE));
+                let (x1, y1) = match rng.range(0..3) {
+                    0 => (rng.range(0..SIDE), y0),
+                    1 => (x0, rng.range(0..SIDE)),
+                    _ => {
+                        let x1 = rng.range(0..SIDE);
+                        let len = x0.abs_diff(x1);
+                        if len <= y0 && (SIDE <= y0 + len || rng.ratio(1, 2)) {
+                            (x1, y0 - len)
+                        } else if y0 + len < SIDE {
+                            (x1, y0 + len)
+                        } else {
+                            (x0, y0)
+                        }
+                    }
+                };
+                format!("{},{} -> {},{}\n", x0, y0, x1, y1)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,247 @@
+//! Day 5: Hydrothermal Venture. Counts where lines of vents overlap.
+
+#![warn(missing_docs)]
+
+mod generator;
+
+use std::collections::HashMap;
+
+use common::{Metadata, ParseError, PuzzleError, Solution, SolveError};
+
+/// Solver of day 5, for the runner and generic code over [`Solution`].
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    const METADATA: Metadata = Metadata {
+        title: "Hydrothermal Venture",
+        input: "one `x1,y1 -> x2,y2` line of vents per line, horizontal, vertical or diagonal, coordinates from 0 to 9999",
+        example: include_str!("../example.txt"),
+        answers: ["5", "12"],
+    };
+
+    type Input<'a> = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check(input)
+    }
+
+    fn stream1(lines: &mut dyn Iterator<Item = String>) -> Option<Result<usize, PuzzleError>> {
+        Some(common::stream(lines, parse_vents, |lines| {
+            Ok(solve(lines, &horizontal_or_vertical))
+        }))
+    }
+
+    fn stream2(lines: &mut dyn Iterator<Item = String>) -> Option<Result<usize, PuzzleError>> {
+        Some(common::stream(lines, parse_vents, |lines| {
+            Ok(solve(lines, &always_true))
+        }))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+/// `(x, y)` coordinates on the ocean floor.
+pub type Point = (isize, isize);
+
+/// Line of vents from one end to the other, both included.
+pub type Line = (Point, Point);
+
+/// Largest coordinate on the ocean floor, which keeps lines short enough to
+/// be walked point by point.
+const MAX_COORDINATE: isize = 9999;
+
+/// Reads one `x1,y1 -> x2,y2` line of vents per line. Lines must be
+/// horizontal, vertical or diagonal at 45 degrees.
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    input.lines().map(|l| parse_line(input, l)).collect()
+}
+
+/// Every line [`parse`] would stop at.
+pub fn check(input: &str) -> Vec<ParseError> {
+    input
+        .lines()
+        .filter_map(|l| parse_line(input, l).err())
+        .collect()
+}
+
+fn parse_vents(l: String) -> Result<Line, ParseError> {
+    parse_line(&l, &l)
+}
+
+fn parse_line(input: &str, l: &str) -> Result<Line, ParseError> {
+    let error = |token: &str, expected: &str| ParseError::at(Day05::DAY, input, token, expected);
+
+    let coordinate = |c: &str| match c.parse::<isize>() {
+        Ok(c) if (0..=MAX_COORDINATE).contains(&c) => Ok(c),
+        Ok(_) => Err(error(
+            c,
+            &format!("coordinate from 0 to {}", MAX_COORDINATE),
+        )),
+        Err(_) => Err(error(c, "integer")),
+    };
+
+    let point = |xy: &str| {
+        let (x, y) = xy
+            .split_once(',')
+            .ok_or_else(|| error(xy, "x,y coordinates"))?;
+        Ok((coordinate(x)?, coordinate(y)?))
+    };
+
+    let (start, end) = l
+        .split_once(" -> ")
+        .ok_or_else(|| error(l, "x1,y1 -> x2,y2"))?;
+    let ((x0, y0), (x1, y1)) = (point(start)?, point(end)?);
+    if x0 != x1 && y0 != y1 && x0.abs_diff(x1) != y0.abs_diff(y1) {
+        return Err(error(l, "horizontal, vertical or diagonal line"));
+    }
+    Ok(((x0, y0), (x1, y1)))
+}
+
+/// Number of points where horizontal or vertical lines overlap.
+pub fn part1(coordinates: &[Line]) -> usize {
+    solve(coordinates.iter().copied(), &horizontal_or_vertical)
+}
+
+fn horizontal_or_vertical(((x0, y0), (x1, y1)): Line) -> bool {
+    x0 == x1 || y0 == y1
+}
+
+/// Number of points where lines overlap, diagonals included.
+pub fn part2(coordinates: &[Line]) -> usize {
+    solve(coordinates.iter().copied(), &always_true)
+}
+
+fn always_true(_: Line) -> bool {
+    true
+}
+
+/// Memory grows with the points covered, not with the number of lines.
+fn solve(coordinates: impl Iterator<Item = Line>, filter: &dyn Fn(Line) -> bool) -> usize {
+    coordinates
+        .filter(|&x| filter(x))
+        .fold(HashMap::new(), |mut acc, coordinates| {
+            for point in points(coordinates) {
+                *acc.entry(point).or_insert(0) += 1;
+            }
+
+            acc
+        })
+        .values()
+        .filter(|&&count| count > 1)
+        .count()
+}
+
+/// Points of a horizontal, vertical or diagonal `line`, both ends included.
+pub fn points(line: Line) -> impl Iterator<Item = Point> {
+    let ((x_bottom, y_bottom), (x_top, y_top)) = sort(line);
+    let (dx, dy) = increments(x_bottom, y_bottom, x_top, y_top);
+    let len = x_bottom.abs_diff(x_top).max(y_bottom.abs_diff(y_top)) as isize;
+
+    (0..=len).map(move |i| (x_bottom + i * dx, y_bottom + i * dy))
+}
+
+fn sort(((x0, y0), (x1, y1)): Line) -> Line {
+    match y0.cmp(&y1) {
+        std::cmp::Ordering::Less => ((x0, y0), (x1, y1)),
+        std::cmp::Ordering::Equal => {
+            if x0 <= x1 {
+                ((x0, y0), (x1, y1))
+            } else {
+                ((x1, y1), (x0, y0))
+            }
+        }
+        std::cmp::Ordering::Greater => ((x1, y1), (x0, y0)),
+    }
+}
+
+fn increments(x_bottom: isize, y_bottom: isize, x_top: isize, y_top: isize) -> Point {
+    if x_bottom == x_top {
+        (0, 1)
+    } else if y_bottom == y_top {
+        (1, 0)
+    } else if x_bottom < x_top {
+        (1, 1)
+    } else {
+        (-1, 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use common::{Generator, Rng};
+
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let input = parse(Day05::METADATA.example).unwrap();
+
+        assert_eq!(part1(&input), 5)
+    }
+
+    #[test]
+    fn part2_test() {
+        let input = parse(Day05::METADATA.example).unwrap();
+
+        assert_eq!(part2(&input), 12)
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("0,9 -> 5,9\n8,0 -> 0\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 8));
+        assert_eq!(error.expected, "x,y coordinates");
+
+        let error = parse("0,9 -> 5,9\n8,0 0,8\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.text, "8,0 0,8");
+
+        let error = parse("0,9 -> 5,9\n8,0 -> 0,7\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "horizontal, vertical or diagonal line");
+
+        let error = parse("0,9 -> 5,-9\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 10));
+        assert_eq!(error.expected, "coordinate from 0 to 9999");
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day05::generate(&mut Rng::new(seed), 100);
+            let input = Day05::parse(&input).unwrap();
+            Day05::part1(&input).unwrap();
+            Day05::part2(&input).unwrap();
+        }
+    }
+
+    #[test]
+    fn check_test() {
+        let errors = check("0,9 -> 5,9\n8,0 -> 0,7\n1,1 -> 3,3\n0,x -> 1,1\n");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column, e.expected.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (2, 1, "horizontal, vertical or diagonal line"),
+                (4, 3, "integer"),
+            ]
+        );
+    }
+}
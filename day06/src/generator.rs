@@ -0,0 +1,13 @@
+use common::{Generator, Rng};
+
+use crate::Day06;
+
+impl Generator for Day06 {
+    /// `size` lanternfish timers between 1 and 5.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let timers = (0..size.max(1))
+            .map(|_| rng.range(1..6).to_string())
+            .collect::<Vec<_>>();
+        format!("{}\n", timers.join(","))
+    }
+}
@@ -0,0 +1,167 @@
+//! Day 6: Lanternfish. Counts lanternfish after they spawn for many days.
+
+#![warn(missing_docs)]
+
+mod generator;
+
+use common::{Metadata, ParseError, Solution, SolveError};
+
+/// Solver of day 6, for the runner and generic code over [`Solution`].
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    const METADATA: Metadata = Metadata {
+        title: "Lanternfish",
+        input: "comma separated timers from 0 to 8",
+        example: include_str!("../example.txt"),
+        answers: ["5934", "26984457539"],
+    };
+
+    type Input<'a> = Model;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(*input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(*input))
+    }
+}
+
+/// Number of lanternfish per internal timer, from 0 to 8.
+pub type Model = [usize; 9];
+
+/// Number of lanternfish after 80 days.
+pub fn part1(model: Model) -> usize {
+    solve(model, 80)
+}
+
+/// Number of lanternfish after 256 days.
+pub fn part2(model: Model) -> usize {
+    solve(model, 256)
+}
+
+fn solve(model: Model, days: usize) -> usize {
+    (0..days).fold(model, |m, _| iteration(m)).into_iter().sum()
+}
+
+fn iteration(m: Model) -> Model {
+    [m[1], m[2], m[3], m[4], m[5], m[6], m[7] + m[0], m[8], m[0]]
+}
+
+/// Reads the comma separated timers of the lanternfish.
+pub fn parse(input: &str) -> Result<Model, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|n| parse_timer(input, n))
+        .try_fold([0usize; 9], |mut acc, n| {
+            acc[n?] += 1;
+            Ok(acc)
+        })
+}
+
+/// Every timer [`parse`] would stop at.
+pub fn check(input: &str) -> Vec<ParseError> {
+    input
+        .trim()
+        .split(',')
+        .filter_map(|n| parse_timer(input, n).err())
+        .collect()
+}
+
+fn parse_timer(input: &str, n: &str) -> Result<usize, ParseError> {
+    match n.parse::<usize>() {
+        Ok(n) if n < 9 => Ok(n),
+        _ => Err(ParseError::at(Day06::DAY, input, n, "timer from 0 to 8")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use common::{Generator, Rng};
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        assert_eq!(part1(parse(Day06::METADATA.example).unwrap()), 5934);
+    }
+
+    #[test]
+    fn part2_test() {
+        assert_eq!(part2(parse(Day06::METADATA.example).unwrap()), 26984457539);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("3,4,9,1,2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        assert_eq!(error.text, "9");
+    }
+
+    #[test]
+    fn check_test() {
+        let errors = check("3,4,9,1,x,2\n");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column, e.text.as_str()))
+                .collect::<Vec<_>>(),
+            [(1, 5, "9"), (1, 9, "x")]
+        );
+    }
+
+    /// Simulates every lanternfish one by one.
+    fn reference(timers: &[usize], days: usize) -> usize {
+        let mut fishes = timers.to_vec();
+        for _ in 0..days {
+            let mut born = 0;
+            for timer in &mut fishes {
+                if *timer == 0 {
+                    *timer = 6;
+                    born += 1;
+                } else {
+                    *timer -= 1;
+                }
+            }
+            fishes.extend(std::iter::repeat_n(8, born));
+        }
+        fishes.len()
+    }
+
+    proptest! {
+        #[test]
+        fn solve_reference_test(timers in vec(0usize..9, 0..10), days in 0usize..=80) {
+            let model = timers.iter().fold([0; 9], |mut acc, &t| {
+                acc[t] += 1;
+                acc
+            });
+            prop_assert_eq!(solve(model, days), reference(&timers, days));
+        }
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day06::generate(&mut Rng::new(seed), 100);
+            let input = Day06::parse(&input).unwrap();
+            Day06::part1(&input).unwrap();
+            Day06::part2(&input).unwrap();
+        }
+    }
+}
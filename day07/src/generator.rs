@@ -0,0 +1,13 @@
+use common::{Generator, Rng};
+
+use crate::Day07;
+
+impl Generator for Day07 {
+    /// `size` crab positions below 2000.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let positions = (0..size.max(1))
+            .map(|_| rng.range(0..2000).to_string())
+            .collect::<Vec<_>>();
+        format!("{}\n", positions.join(","))
+    }
+}
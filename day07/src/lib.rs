@@ -0,0 +1,178 @@
+//! Day 7: The Treachery of Whales. Aligns crabs at the cheapest position.
+
+#![warn(missing_docs)]
+
+mod generator;
+
+use common::{Metadata, ParseError, Solution, SolveError};
+
+/// Solver of day 7, for the runner and generic code over [`Solution`].
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    const METADATA: Metadata = Metadata {
+        title: "The Treachery of Whales",
+        input: "comma separated positions from 0 to 9999",
+        example: include_str!("../example.txt"),
+        answers: ["37", "168"],
+    };
+
+    type Input<'a> = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input.clone()))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+/// Least fuel to align the crabs, each step costing 1.
+pub fn part1(mut positions: Vec<usize>) -> usize {
+    positions.sort_unstable();
+    // Any position between the two middle crabs is optimal for an even count.
+    let median = positions[positions.len() / 2];
+
+    positions.into_iter().map(|n| n.abs_diff(median)).sum()
+}
+
+/// Least fuel to align the crabs, the nth step of a crab costing n.
+pub fn part2(positions: &[usize]) -> usize {
+    let sum: usize = positions.iter().sum();
+    let mean_floor = sum / positions.len();
+    let mean_ceil = mean_floor + 1;
+
+    cost2(positions, mean_floor).min(cost2(positions, mean_ceil))
+}
+
+fn cost2(positions: &[usize], target: usize) -> usize {
+    positions
+        .iter()
+        .map(|&n| {
+            let d = n.abs_diff(target);
+            (d * (d + 1)) / 2
+        })
+        .sum()
+}
+
+/// Largest position of a crab, which keeps the fuel costs from overflowing.
+const MAX_POSITION: usize = 9999;
+
+/// Reads the comma separated horizontal positions of the crabs.
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|n| parse_position(input, n))
+        .collect()
+}
+
+/// Every position [`parse`] would stop at.
+pub fn check(input: &str) -> Vec<ParseError> {
+    input
+        .trim()
+        .split(',')
+        .filter_map(|n| parse_position(input, n).err())
+        .collect()
+}
+
+fn parse_position(input: &str, n: &str) -> Result<usize, ParseError> {
+    let error = |token: &str, expected: &str| ParseError::at(Day07::DAY, input, token, expected);
+
+    match n.parse() {
+        Ok(p) if p <= MAX_POSITION => Ok(p),
+        Ok(_) => Err(error(n, &format!("position from 0 to {}", MAX_POSITION))),
+        Err(_) => Err(error(n, "position as integer")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use common::{Generator, Rng};
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        assert_eq!(part1(parse(Day07::METADATA.example).unwrap()), 37);
+    }
+
+    #[test]
+    fn part2_test() {
+        assert_eq!(part2(&parse(Day07::METADATA.example).unwrap()), 168);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("16,1,2,,4").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 8));
+        assert_eq!(error.text, "");
+
+        let error = parse("16,10000").unwrap_err();
+        assert_eq!(error.expected, "position from 0 to 9999");
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn check_test() {
+        let errors = check("16,1,,4,10000,x\n");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column, e.expected.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (1, 6, "position as integer"),
+                (1, 9, "position from 0 to 9999"),
+                (1, 15, "position as integer"),
+            ]
+        );
+    }
+
+    /// Tries every position between the leftmost and rightmost crab.
+    fn reference(positions: &[usize], cost: fn(usize) -> usize) -> usize {
+        let min = *positions.iter().min().unwrap();
+        let max = *positions.iter().max().unwrap();
+        (min..=max)
+            .map(|target| positions.iter().map(|&n| cost(n.abs_diff(target))).sum())
+            .min()
+            .unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn part1_reference_test(positions in vec(0usize..2000, 1..100)) {
+            prop_assert_eq!(part1(positions.clone()), reference(&positions, |d| d));
+        }
+
+        #[test]
+        fn part2_reference_test(positions in vec(0usize..2000, 1..100)) {
+            prop_assert_eq!(part2(&positions), reference(&positions, |d| d * (d + 1) / 2));
+        }
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day07::generate(&mut Rng::new(seed), 100);
+            let input = Day07::parse(&input).unwrap();
+            Day07::part1(&input).unwrap();
+            Day07::part2(&input).unwrap();
+        }
+    }
+}
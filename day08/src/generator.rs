@@ -0,0 +1,46 @@
+use common::{Generator, Rng};
+
+use crate::Day08;
+
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+impl Generator for Day08 {
+    /// `size` displays, each wired with its own random permutation of the
+    /// segments, showing the ten digits in random order then four outputs.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let mut wires = ['a', 'b', 'c', 'd', 'e', 'f', 'g'];
+                rng.shuffle(&mut wires);
+
+                let mut digits = (0..10).collect::<Vec<_>>();
+                rng.shuffle(&mut digits);
+
+                let patterns = digits
+                    .into_iter()
+                    .map(|d| scramble(rng, &wires, d))
+                    .collect::<Vec<_>>();
+                let output = (0..4)
+                    .map(|_| {
+                        let d = rng.range(0..10);
+                        scramble(rng, &wires, d)
+                    })
+                    .collect::<Vec<_>>();
+
+                format!("{} | {}\n", patterns.join(" "), output.join(" "))
+            })
+            .collect()
+    }
+}
+
+/// Segments lit for `digit` once rewired, in random order.
+fn scramble(rng: &mut Rng, wires: &[char; 7], digit: usize) -> String {
+    let mut segments = DIGITS[digit]
+        .bytes()
+        .map(|s| wires[(s - b'a') as usize])
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut segments);
+    segments.into_iter().collect()
+}
@@ -0,0 +1,337 @@
+//! Day 8: Seven Segment Search. Decodes displays whose wires are scrambled.
+
+#![warn(missing_docs)]
+
+mod generator;
+
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet},
+};
+
+use common::{Metadata, ParseError, PuzzleError, Solution, SolveError};
+
+/// Solver of day 8, for the runner and generic code over [`Solution`].
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    const METADATA: Metadata = Metadata {
+        title: "Seven Segment Search",
+        input: "one display per line: 10 patterns, `|`, then 4 output digits, made of segments `a` to `g`",
+        example: include_str!("../example.txt"),
+        answers: ["26", "61229"],
+    };
+
+    type Input<'a> = Vec<Entry>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        part2(input)
+    }
+
+    fn stream1(lines: &mut dyn Iterator<Item = String>) -> Option<Result<usize, PuzzleError>> {
+        Some(common::stream(lines, parse_entry, |entries| {
+            Ok(count_unique(entries))
+        }))
+    }
+
+    fn stream2(lines: &mut dyn Iterator<Item = String>) -> Option<Result<usize, PuzzleError>> {
+        Some(common::stream(lines, parse_entry, |entries| {
+            sum_outputs(entries)
+        }))
+    }
+}
+
+/// Display of one line: the 10 patterns of the digits, then the 4 digits of
+/// its output, all with their segments scrambled the same way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Segments of each of the 10 digits, the digits in no particular order.
+    pub patterns: Vec<String>,
+    /// Segments of the output digits.
+    pub output: Vec<String>,
+}
+
+/// Reads one entry per line: 10 unique patterns, `|`, then 4 output digits.
+pub fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+    input.lines().map(|l| parse_line(input, l)).collect()
+}
+
+/// Every line [`parse`] would stop at.
+pub fn check(input: &str) -> Vec<ParseError> {
+    input
+        .lines()
+        .filter_map(|l| parse_line(input, l).err())
+        .collect()
+}
+
+fn parse_line<'a>(input: &str, l: &'a str) -> Result<Entry, ParseError> {
+    let error = |token: &str, expected: &str| ParseError::at(Day08::DAY, input, token, expected);
+
+    let (first_part, second_part) = l
+        .split_once(" | ")
+        .ok_or_else(|| error(l, "patterns | output"))?;
+
+    let words = |part: &'a str, len: usize| {
+        let words = part.split_ascii_whitespace().collect::<Vec<_>>();
+        if words.len() != len {
+            return Err(error(part, &format!("{} words", len)));
+        }
+
+        for w in &words {
+            if let Some((i, c)) = w.char_indices().find(|&(_, c)| !('a'..='g').contains(&c)) {
+                return Err(error(&w[i..i + c.len_utf8()], "segment from a to g"));
+            }
+        }
+
+        Ok(words)
+    };
+    let patterns = words(first_part, 10)?;
+    let output = words(second_part, 4)?;
+
+    let mut seen = HashSet::new();
+    if let Some(w) = patterns.iter().find(|w| !seen.insert(sort(w))) {
+        return Err(error(w, "pattern not yet on the line"));
+    }
+
+    Ok(Entry {
+        patterns: patterns.into_iter().map(String::from).collect(),
+        output: output.into_iter().map(String::from).collect(),
+    })
+}
+
+fn parse_entry(l: String) -> Result<Entry, ParseError> {
+    parse_line(&l, &l)
+}
+
+/// Number of 1, 4, 7 and 8 among the output digits.
+pub fn part1(entries: &[Entry]) -> usize {
+    count_unique(entries.iter())
+}
+
+fn count_unique(entries: impl Iterator<Item = impl Borrow<Entry>>) -> usize {
+    entries
+        .map(|e| {
+            e.borrow()
+                .output
+                .iter()
+                .filter(|w| {
+                    w.len() == 2 // number 1
+                        || w.len() == 4 // number 4
+                        || w.len() == 3 // number 7
+                        || w.len() == 7 // number 8
+                })
+                .count()
+        })
+        .sum()
+}
+
+/// Sum of the decoded output values.
+pub fn part2(entries: &[Entry]) -> Result<usize, SolveError> {
+    sum_outputs(entries.iter())
+}
+
+fn sum_outputs(entries: impl Iterator<Item = impl Borrow<Entry>>) -> Result<usize, SolveError> {
+    entries
+        .enumerate()
+        .map(|(i, e)| {
+            let e = e.borrow();
+            decode(&e.patterns, &e.output).ok_or_else(|| {
+                SolveError::new(Day08::DAY, 2, format!("cannot decode line {}", i + 1))
+            })
+        })
+        .sum()
+}
+
+/// Value of the `output` digits, once the wiring is deduced from the 10
+/// unique `patterns`. None when the patterns are not the 10 digits.
+fn decode(patterns: &[String], output: &[String]) -> Option<usize> {
+    let size_to_segments: HashMap<usize, Vec<&str>> =
+        patterns.iter().fold(HashMap::new(), |mut acc, w| {
+            acc.entry(w.len()).or_insert_with(Vec::new).push(w);
+            acc
+        });
+
+    let mut segments_to_number: HashMap<String, usize> = HashMap::new();
+
+    // (0) uniques by segment count: 1 4 7 8
+    let one_as_segment = size_to_segments.get(&2)?[0];
+    segments_to_number.insert(sort(one_as_segment), 1);
+
+    let four_as_segments = size_to_segments.get(&4)?[0];
+    segments_to_number.insert(sort(four_as_segments), 4);
+
+    let seven_as_segments = size_to_segments.get(&3)?[0];
+    segments_to_number.insert(sort(seven_as_segments), 7);
+
+    let eight_as_segments = size_to_segments.get(&7)?[0];
+    segments_to_number.insert(sort(eight_as_segments), 8);
+
+    // (1) find permutation of 0, 6, 9 such that 9 - 7 - 4 = g will
+    // give 9 and g because {0, 6} - 7 - 4 = 2 letters.
+    let zero_six_nine_as_segments = size_to_segments.get(&6)?; // 0, 6, 9 are size 6
+
+    let nine_as_segments = zero_six_nine_as_segments
+        .iter()
+        .find(|segments| minus(&minus(segments, seven_as_segments), four_as_segments).len() == 1)?;
+    segments_to_number.insert(sort(nine_as_segments), 9);
+
+    // (2) 3 - 7 = d + g will give 3 because {2,3} - 7 = 3 letters.
+    let two_three_five_as_segments = size_to_segments.get(&5)?; // 2, 3, 5 are size 5
+
+    let three_as_segments = two_three_five_as_segments
+        .iter()
+        .find(|segments| minus(segments, seven_as_segments).len() == 2)?;
+    segments_to_number.insert(sort(three_as_segments), 3);
+
+    // (3) 5 - 9 = empty will give 5 and then 2 (last of the same size
+    // of 2 and 3).
+    let five_as_segments = two_three_five_as_segments
+        .iter()
+        .filter(|&segments| segments != three_as_segments)
+        .find(|segments| minus(segments, nine_as_segments).is_empty())?;
+    segments_to_number.insert(sort(five_as_segments), 5);
+
+    let two_as_segments = two_three_five_as_segments
+        .iter()
+        .find(|&segments| segments != three_as_segments && segments != five_as_segments)?;
+    segments_to_number.insert(sort(two_as_segments), 2);
+
+    // (4) 6 - 5 = e will give 6 and 0 (last one).
+    let six_as_segments = zero_six_nine_as_segments
+        .iter()
+        .filter(|&segments| segments != nine_as_segments)
+        .find(|segments| minus(segments, five_as_segments).len() == 1)?;
+    segments_to_number.insert(sort(six_as_segments), 6);
+
+    let zero_as_segments = zero_six_nine_as_segments
+        .iter()
+        .find(|&segments| segments != six_as_segments && segments != nine_as_segments)?;
+    segments_to_number.insert(sort(zero_as_segments), 0);
+
+    // Repeated patterns deduce fewer than 10 digits.
+    if segments_to_number.len() != 10 {
+        return None;
+    }
+
+    output.iter().try_fold(0, |n, segments| {
+        let digit = segments_to_number.get(&sort(segments))?;
+        Some(10 * n + digit)
+    })
+}
+
+fn minus(on: &str, off: &str) -> String {
+    let on = on.chars().collect::<HashSet<_>>();
+    let off = off.chars().collect::<HashSet<_>>();
+    on.difference(&off).collect()
+}
+
+fn sort(s: &str) -> String {
+    let mut s = s.chars().collect::<Vec<_>>();
+    s.sort_unstable();
+    s.into_iter().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use common::{Generator, Rng};
+
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let entries = parse(Day08::METADATA.example).unwrap();
+
+        assert_eq!(part1(&entries), 26);
+    }
+
+    #[test]
+    fn part2_test() {
+        let entries = parse(Day08::METADATA.example).unwrap();
+
+        assert_eq!(part2(&entries), Ok(61229));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse(
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd",
+        )
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (1, 62));
+        assert_eq!(error.expected, "4 words");
+
+        let error = parse(
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gxbe",
+        )
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (1, 84));
+        assert_eq!(error.text, "x");
+
+        let error = parse(
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd eb | fdgacbe cefdb cefbgd gcbe",
+        )
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (1, 56));
+        assert_eq!(error.expected, "pattern not yet on the line");
+    }
+
+    #[test]
+    fn check_test() {
+        let errors = check(
+            "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb fdgacbe cefdb cefbgd gcbe
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gxbe
+be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd | fdgacbe cefdb cefbgd gcbe",
+        );
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column, e.expected.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (2, 1, "patterns | output"),
+                (3, 84, "segment from a to g"),
+                (4, 1, "10 words"),
+            ]
+        );
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day08::generate(&mut Rng::new(seed), 20);
+            let input = Day08::parse(&input).unwrap();
+            Day08::part1(&input).unwrap();
+            Day08::part2(&input).unwrap();
+        }
+    }
+
+    #[test]
+    fn solve_error_test() {
+        let input = parse(
+            "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf
+ab abc abcd abcde abcdef abcdefg a b c d | ab ab ab ab",
+        )
+        .unwrap();
+        assert_eq!(
+            part2(&input).unwrap_err().to_string(),
+            "Day 08, part 2: cannot decode line 2"
+        );
+    }
+}
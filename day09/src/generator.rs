@@ -0,0 +1,50 @@
+use common::{Generator, Rng};
+
+use crate::Day09;
+
+impl Generator for Day09 {
+    /// A `size` by `size` heightmap of rectangular basins walled off by
+    /// ridges of 9, each sloping up from a single low point as the puzzle
+    /// assumes. Each row of basins has widths of its own, so the ridges
+    /// between basins rarely line up from one row to the next.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let mut heights = vec![vec![9; size]; size];
+
+        let mut top = 0;
+        while top < size {
+            let bottom = (top + rng.range(1..9)).min(size);
+
+            let mut left = 0;
+            while left < size {
+                let right = (left + rng.range(1..9)).min(size);
+                let (x_low, y_low) = (rng.range(left..right), rng.range(top..bottom));
+
+                // Any other point of the rectangle has a lower neighbor
+                // towards the low point, or an equal one on the plateau of 8.
+                for (y, row) in heights.iter_mut().enumerate().take(bottom).skip(top) {
+                    for (x, height) in row.iter_mut().enumerate().take(right).skip(left) {
+                        *height = (x.abs_diff(x_low) + y.abs_diff(y_low)).min(8) as u8;
+                    }
+                }
+
+                // Leaves a ridge column before the next basin.
+                left = right + 1;
+            }
+
+            top = bottom + 1;
+        }
+
+        heights
+            .into_iter()
+            .map(|row| {
+                let mut row = row
+                    .into_iter()
+                    .map(|h| char::from(b'0' + h))
+                    .collect::<String>();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,144 @@
+//! Day 9: Smoke Basin. Finds the low points and basins of a heightmap.
+
+#![warn(missing_docs)]
+
+mod generator;
+
+use std::collections::HashSet;
+
+use common::{Metadata, ParseError, Solution, SolveError};
+use grid::Grid;
+
+/// Solver of day 9, for the runner and generic code over [`Solution`].
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    const METADATA: Metadata = Metadata {
+        title: "Smoke Basin",
+        input: "heightmap of one row of digits per line, rows of the same length",
+        example: include_str!("../example.txt"),
+        answers: ["15", "1134"],
+    };
+
+    type Input<'a> = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        Grid::check_digits(Self::DAY, input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        Ok(part2(input))
+    }
+}
+
+/// Reads a heightmap of one row of digits per line.
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_digits(Day09::DAY, input)
+}
+
+/// Sum of the risk levels, height plus one, of the low points.
+pub fn part1(grid: &Grid<u8>) -> usize {
+    lows(grid).map(|(x, y)| usize::from(grid[(x, y)]) + 1).sum()
+}
+
+/// Points lower than all their neighbors.
+pub fn lows(grid: &Grid<u8>) -> impl Iterator<Item = (usize, usize)> + '_ {
+    grid.positions().filter(|&(x, y)| {
+        grid.neighbors4(x, y)
+            .all(|neighbor| grid[(x, y)] < grid[neighbor])
+    })
+}
+
+/// Product of the sizes of the three largest basins.
+pub fn part2(grid: &Grid<u8>) -> usize {
+    let mut bassins = lows(grid)
+        .map(|(x, y)| basin(grid, x, y).len())
+        .collect::<Vec<_>>();
+
+    bassins.sort_unstable();
+
+    bassins.into_iter().rev().take(3).product()
+}
+
+/// Points of the basin around the low point `(x_low, y_low)`, in the order
+/// a flood from the low point reaches them.
+pub fn basin(grid: &Grid<u8>, x_low: usize, y_low: usize) -> Vec<(usize, usize)> {
+    let mut visited = HashSet::new();
+    let mut basin = Vec::new();
+    let mut to_visit = vec![(x_low, y_low)];
+
+    while let Some((x, y)) = to_visit.pop() {
+        if !visited.insert((x, y)) {
+            continue;
+        }
+
+        basin.push((x, y));
+
+        for neighbor in grid.neighbors4(x, y) {
+            if grid[neighbor] != 9 && !visited.contains(&neighbor) {
+                to_visit.push(neighbor);
+            }
+        }
+    }
+
+    basin
+}
+
+#[cfg(test)]
+mod test {
+    use common::{Generator, Rng};
+
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let grid = parse(Day09::METADATA.example).unwrap();
+
+        assert_eq!(part1(&grid), 15);
+    }
+
+    #[test]
+    fn part2_test() {
+        let grid = parse(Day09::METADATA.example).unwrap();
+
+        assert_eq!(part2(&grid), 1134);
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("2199943210\n3987894921\n98567a9892").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
+        assert_eq!(error.text, "a");
+
+        let error = parse("2199943210\n398789492").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "row of 10 digits");
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day09::generate(&mut Rng::new(seed), 20);
+            let input = Day09::parse(&input).unwrap();
+            Day09::part1(&input).unwrap();
+            Day09::part2(&input).unwrap();
+
+            // Basins are walled off, each around its own low point.
+            let sizes = lows(&input).map(|(x, y)| basin(&input, x, y).len());
+            let cells = input.positions().filter(|&p| input[p] != 9).count();
+            assert_eq!(sizes.sum::<usize>(), cells);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use common::{Generator, Rng};
+
+use crate::Day10;
+
+const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Most chunks left open at once, the most whose autocomplete score fits in
+/// 64 bits: with `n` open chunks it is at most 5^n - 1, and 5^28 overflows.
+const MAX_OPEN: usize = 27;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Complete,
+    Corrupted,
+    Incomplete,
+}
+
+impl Generator for Day10 {
+    /// `size` lines of chunks, each complete, corrupted or incomplete. The
+    /// number of incomplete lines is odd so that their scores have a middle.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut kinds = (0..size.max(1))
+            .map(|_| *rng.choose(&[Kind::Complete, Kind::Corrupted, Kind::Incomplete]))
+            .collect::<Vec<_>>();
+
+        let incomplete = kinds.iter().filter(|&&k| k == Kind::Incomplete).count();
+        if incomplete % 2 == 0 {
+            kinds[0] = match kinds[0] {
+                Kind::Incomplete => Kind::Corrupted,
+                _ => Kind::Incomplete,
+            };
+        }
+
+        kinds
+            .into_iter()
+            .map(|kind| {
+                let mut l = line(rng, kind);
+                l.push('\n');
+                l
+            })
+            .collect()
+    }
+}
+
+fn line(rng: &mut Rng, kind: Kind) -> String {
+    let mut line = String::new();
+    let mut stack = Vec::new();
+
+    for _ in 0..rng.range(10..60) {
+        match stack.pop() {
+            Some(close) if stack.len() + 1 >= MAX_OPEN || rng.ratio(1, 2) => line.push(close),
+            Some(close) => {
+                stack.push(close);
+                open(rng, &mut line, &mut stack);
+            }
+            None => open(rng, &mut line, &mut stack),
+        }
+    }
+
+    match kind {
+        Kind::Complete => line.extend(stack.into_iter().rev()),
+        Kind::Corrupted => {
+            if stack.is_empty() {
+                open(rng, &mut line, &mut stack);
+            }
+            let expected = stack[stack.len() - 1];
+            let wrong = PAIRS
+                .iter()
+                .map(|&(_, close)| close)
+                .filter(|&close| close != expected)
+                .collect::<Vec<_>>();
+            line.push(*rng.choose(&wrong));
+        }
+        Kind::Incomplete => {
+            if stack.is_empty() {
+                open(rng, &mut line, &mut stack);
+            }
+        }
+    }
+
+    line
+}
+
+/// Opens a random chunk, remembering how to close it.
+fn open(rng: &mut Rng, line: &mut String, stack: &mut Vec<char>) {
+    let (open, close) = *rng.choose(&PAIRS);
+    line.push(open);
+    stack.push(close);
+}
@@ -0,0 +1,266 @@
+//! Day 10: Syntax Scoring. Scores corrupted and incomplete chunks.
+
+#![warn(missing_docs)]
+
+mod generator;
+
+use common::{Metadata, ParseError, PuzzleError, Solution, SolveError};
+
+/// Solver of day 10, for the runner and generic code over [`Solution`].
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    const METADATA: Metadata = Metadata {
+        title: "Syntax Scoring",
+        input: "one line of delimiters `()[]{}<>` per line",
+        example: include_str!("../example.txt"),
+        answers: ["26397", "288957"],
+    };
+
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        part2(input)
+    }
+
+    fn stream1(lines: &mut dyn Iterator<Item = String>) -> Option<Result<usize, PuzzleError>> {
+        Some(common::stream(lines, parse_chunks, |lines| {
+            error_score(lines)
+        }))
+    }
+
+    fn stream2(lines: &mut dyn Iterator<Item = String>) -> Option<Result<usize, PuzzleError>> {
+        Some(common::stream(lines, parse_chunks, |lines| {
+            autocomplete_score(lines)
+        }))
+    }
+}
+
+/// Checks that every line holds only delimiters. The lines are scored from
+/// the text itself.
+pub fn parse(input: &str) -> Result<&str, ParseError> {
+    for l in input.lines() {
+        parse_line(input, l)?;
+    }
+
+    Ok(input)
+}
+
+/// Every line [`parse`] would stop at.
+pub fn check(input: &str) -> Vec<ParseError> {
+    input
+        .lines()
+        .filter_map(|l| parse_line(input, l).err())
+        .collect()
+}
+
+fn parse_line(input: &str, l: &str) -> Result<(), ParseError> {
+    match l.char_indices().find(|&(_, c)| !"()[]{}<>".contains(c)) {
+        Some((i, c)) => {
+            let token = &l[i..i + c.len_utf8()];
+            Err(ParseError::at(Day10::DAY, input, token, "delimiter"))
+        }
+        None => Ok(()),
+    }
+}
+
+fn parse_chunks(l: String) -> Result<String, ParseError> {
+    parse_line(&l, &l).map(|_| l)
+}
+
+/// Syntax error score: sum of the points of the first illegal character of
+/// every corrupted line.
+pub fn part1(input: &str) -> Result<usize, SolveError> {
+    error_score(input.lines())
+}
+
+fn error_score(lines: impl Iterator<Item = impl AsRef<str>>) -> Result<usize, SolveError> {
+    lines
+        .map(|l| status(l.as_ref()).map_err(|c| unknown(1, c)))
+        .filter_map(|s| match s {
+            Ok(Some(Status::Illegal(c))) => Some(Ok(points1(c))),
+            Ok(Some(Status::Incomplete(_))) | Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        })
+        .sum()
+}
+
+/// Middle autocomplete score of the incomplete lines.
+pub fn part2(input: &str) -> Result<usize, SolveError> {
+    autocomplete_score(input.lines())
+}
+
+/// Keeps the score of every incomplete line, to find the middle one.
+fn autocomplete_score(lines: impl Iterator<Item = impl AsRef<str>>) -> Result<usize, SolveError> {
+    let error = |reason: &str| SolveError::new(Day10::DAY, 2, reason);
+
+    let mut points = lines
+        .map(|l| status(l.as_ref()).map_err(|c| unknown(2, c)))
+        .filter_map(|s| match s {
+            Ok(None) | Ok(Some(Status::Illegal(_))) => None,
+            Ok(Some(Status::Incomplete(leftovers))) => Some(
+                leftovers
+                    .into_iter()
+                    .rev()
+                    .try_fold(0usize, |acc, c| acc.checked_mul(5)?.checked_add(points2(c)))
+                    .ok_or_else(|| error("autocomplete score overflows")),
+            ),
+            Err(e) => Some(Err(e)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    points.sort_unstable();
+
+    points
+        .get(points.len() / 2)
+        .copied()
+        .ok_or_else(|| error("no incomplete line"))
+}
+
+fn unknown(part: u8, c: char) -> SolveError {
+    SolveError::new(Day10::DAY, part, format!("unknown delimiter {:?}", c))
+}
+
+enum Status {
+    Illegal(char),
+    Incomplete(Vec<char>),
+}
+
+/// Status of a line, None when complete, or the first character that is not
+/// a delimiter.
+fn status(l: &str) -> Result<Option<Status>, char> {
+    let mut stack = Vec::new();
+
+    for c in l.chars() {
+        match c {
+            '(' | '[' | '{' | '<' => stack.push(c),
+            ')' | ']' | '}' | '>' => {
+                if let Some(open) = stack.pop() {
+                    if !is_match_delimiter(open, c) {
+                        return Ok(Some(Status::Illegal(c)));
+                    }
+                }
+            }
+            c => return Err(c),
+        }
+    }
+
+    if stack.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(Status::Incomplete(stack)))
+    }
+}
+
+fn is_match_delimiter(open: char, close: char) -> bool {
+    matches!(
+        (open, close),
+        ('(', ')') | ('[', ']') | ('{', '}') | ('<', '>')
+    )
+}
+
+fn points1(c: char) -> usize {
+    match c {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        c => unreachable!("{} is not a closing delimiter", c),
+    }
+}
+
+fn points2(c: char) -> usize {
+    match c {
+        '(' | ')' => 1,
+        '[' | ']' => 2,
+        '{' | '}' => 3,
+        '<' | '>' => 4,
+        c => unreachable!("{} is not a delimiter", c),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use common::{Generator, Rng};
+
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let input = Day10::METADATA.example;
+
+        assert_eq!(part1(input), Ok(26397));
+    }
+
+    #[test]
+    fn part2_test() {
+        let input = Day10::METADATA.example;
+
+        assert_eq!(part2(input), Ok(288957));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(x").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 23));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn check_test() {
+        let errors = check(
+            "[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]a{[<{<<[]>>(\n{([(<{}[<>[]}>{[]{[(<()>\n((x[]\n",
+        );
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column, e.text.as_str()))
+                .collect::<Vec<_>>(),
+            [(2, 11, "a"), (4, 3, "x")]
+        );
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day10::generate(&mut Rng::new(seed), 50);
+            let input = Day10::parse(&input).unwrap();
+            Day10::part1(&input).unwrap();
+            Day10::part2(&input).unwrap();
+        }
+
+        // Large inputs leave enough chunks open to overflow scores unless
+        // the generator bounds them.
+        let input = Day10::generate(&mut Rng::new(3), 20_000);
+        Day10::part2(&Day10::parse(&input).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn solve_error_test() {
+        assert_eq!(
+            part2("()\n(]").unwrap_err().to_string(),
+            "Day 10, part 2: no incomplete line"
+        );
+        assert_eq!(
+            part2(&"<".repeat(40)).unwrap_err().reason,
+            "autocomplete score overflows"
+        );
+        assert_eq!(part1("(x").unwrap_err().reason, "unknown delimiter 'x'");
+    }
+}
@@ -0,0 +1,20 @@
+use common::{Generator, Rng};
+
+use crate::Day11;
+
+impl Generator for Day11 {
+    /// A `size` by `size` grid of random digits.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+
+        (0..size)
+            .map(|_| {
+                let mut row = (0..size)
+                    .map(|_| char::from(b'0' + rng.range(0..10) as u8))
+                    .collect::<String>();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
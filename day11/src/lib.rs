@@ -0,0 +1,151 @@
+//! Day 11: Dumbo Octopus. Follows the energy levels of flashing octopuses.
+
+#![warn(missing_docs)]
+
+mod generator;
+
+use common::{Metadata, ParseError, Solution, SolveError};
+use grid::Grid;
+
+/// Solver of day 11, for the runner and generic code over [`Solution`].
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    const METADATA: Metadata = Metadata {
+        title: "Dumbo Octopus",
+        input: "energy levels of one row of digits per line, rows of the same length",
+        example: include_str!("../example.txt"),
+        answers: ["1656", "195"],
+    };
+
+    type Input<'a> = Grid<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        Grid::check_digits(Self::DAY, input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        Ok(part1(input.clone()))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        part2(input.clone())
+    }
+}
+
+/// Number of flashes during the first 100 steps.
+pub fn part1(mut grid: Grid<u8>) -> usize {
+    let mut flashes = 0;
+    for _ in 0..100 {
+        let (g, f) = step(grid);
+        flashes += f;
+        grid = g;
+    }
+    flashes
+}
+
+/// Steps after which octopuses are deemed to never all flash together.
+pub const MAX_STEPS: usize = 10_000;
+
+/// First step during which all octopuses flash.
+pub fn part2(mut grid: Grid<u8>) -> Result<usize, SolveError> {
+    let grid_len = grid.len();
+    for step_number in 1..=MAX_STEPS {
+        let (g, f) = step(grid);
+        if f == grid_len {
+            return Ok(step_number);
+        }
+        grid = g;
+    }
+
+    Err(SolveError::new(
+        Day11::DAY,
+        2,
+        format!("octopuses do not all flash within {} steps", MAX_STEPS),
+    ))
+}
+
+/// Grid after one more step, and the number of flashes during that step.
+pub fn step(mut grid: Grid<u8>) -> (Grid<u8>, usize) {
+    grid.iter_mut().for_each(|energy| *energy += 1);
+
+    // An octopus flashes once, when its energy reaches 10.
+    let mut tens = grid
+        .positions()
+        .filter(|&p| grid[p] == 10)
+        .collect::<Vec<_>>();
+
+    while let Some((x, y)) = tens.pop() {
+        for neighbor in grid.neighbors8(x, y) {
+            grid[neighbor] += 1;
+            if grid[neighbor] == 10 {
+                tens.push(neighbor);
+            }
+        }
+    }
+
+    let mut flashes = 0;
+    for energy in grid.iter_mut().filter(|energy| 9 < **energy) {
+        flashes += 1;
+        *energy = 0;
+    }
+    (grid, flashes)
+}
+
+/// Reads the energy levels, one row of digits per line.
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_digits(Day11::DAY, input)
+}
+
+#[cfg(test)]
+mod test {
+    use common::{Generator, Rng};
+
+    use super::*;
+
+    #[test]
+    fn part1_test() {
+        let grid = parse(Day11::METADATA.example).unwrap();
+
+        assert_eq!(part1(grid), 1656);
+    }
+
+    #[test]
+    fn part2_test() {
+        let grid = parse(Day11::METADATA.example).unwrap();
+
+        assert_eq!(part2(grid), Ok(195));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("2199943210\n3987894921\n98567a9892").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
+        assert_eq!(error.text, "a");
+
+        let error = parse("2199943210\n398789492").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "row of 10 digits");
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day11::generate(&mut Rng::new(seed), 10);
+            let input = Day11::parse(&input).unwrap();
+            Day11::part1(&input).unwrap();
+            // Random octopuses may never all flash at once.
+            if let Err(e) = Day11::part2(&input) {
+                assert_eq!(e.reason, "octopuses do not all flash within 10000 steps");
+            }
+        }
+    }
+}
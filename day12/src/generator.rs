@@ -0,0 +1,58 @@
+use std::collections::HashSet;
+
+use common::{Generator, Rng};
+
+use crate::Day12;
+
+impl Generator for Day12 {
+    /// Paths grow exponentially with the small caves: past 20 of them, random
+    /// cave systems take seconds to solve.
+    const MAX_SIZE: usize = 20;
+
+    /// A cave system with `size` small caves and half as many big ones,
+    /// besides start and end. Big caves are never connected together,
+    /// otherwise paths could go back and forth between them forever.
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.clamp(1, Self::MAX_SIZE);
+        let small = (0..size)
+            .map(|i| name(i, b'a'))
+            .chain(["start".to_string(), "end".to_string()])
+            .collect::<Vec<_>>();
+        let big = (0..size.div_ceil(2))
+            .map(|i| name(i, b'A'))
+            .collect::<Vec<_>>();
+
+        let mut connexions = HashSet::new();
+        let mut connect = |c1: &str, c2: &str| {
+            if c1 != c2 && !connexions.contains(&(c2.to_string(), c1.to_string())) {
+                connexions.insert((c1.to_string(), c2.to_string()));
+            }
+        };
+
+        for c in &small {
+            for _ in 0..2 {
+                connect(c, rng.choose(&small).as_str());
+            }
+        }
+        for c in &big {
+            for _ in 0..2 {
+                connect(c, rng.choose(&small).as_str());
+            }
+        }
+
+        let mut connexions = connexions.into_iter().collect::<Vec<_>>();
+        connexions.sort_unstable();
+        rng.shuffle(&mut connexions);
+
+        connexions
+            .into_iter()
+            .map(|(c1, c2)| format!("{}-{}\n", c1, c2))
+            .collect()
+    }
+}
+
+/// Two letters name, never clashing with start or end.
+fn name(i: usize, first: u8) -> String {
+    let letters = vec![first + (i / 26) as u8, first + (i % 26) as u8];
+    String::from_utf8(letters).expect("Letters are ASCII")
+}
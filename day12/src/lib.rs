@@ -0,0 +1,451 @@
+//! Day 12: Passage Pathing. Counts the paths through a cave system.
+
+#![warn(missing_docs)]
+
+mod generator;
+
+use std::collections::{HashMap, HashSet};
+
+use common::{Metadata, ParseError, Solution, SolveError};
+
+/// Solver of day 12, for the runner and generic code over [`Solution`].
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    const METADATA: Metadata = Metadata {
+        title: "Passage Pathing",
+        input: "one `a-b` connection between caves per line, big caves in capitals",
+        example: include_str!("../example.txt"),
+        answers: ["226", "3509"],
+    };
+
+    type Input<'a> = Caves<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse(input)
+    }
+
+    fn check(input: &str) -> Vec<ParseError> {
+        check(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1, SolveError> {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Answer2, SolveError> {
+        part2(input)
+    }
+}
+
+/// Most small caves besides start and end whose paths are counted once for
+/// every set of small caves they visited. Past it, every path is walked.
+pub const MAX_SMALL_CAVES: usize = 62;
+
+/// Number of paths from `start` to `end` visiting small caves at most once.
+pub fn part1(caves: &Caves) -> Result<usize, SolveError> {
+    Paths::new(caves, 1)?.count()
+}
+
+/// Number of paths from `start` to `end` where a single small cave may be
+/// visited twice.
+pub fn part2(caves: &Caves) -> Result<usize, SolveError> {
+    Paths::new(caves, 2)?.count()
+}
+
+/// Counter of the paths between small caves, numbered with `start` and `end`
+/// last. Big caves are only ever connected to small ones, so a path through
+/// one is a step between two small caves.
+struct Paths {
+    part: u8,
+    /// Number of ways to go from a small cave to another, either directly or
+    /// through a big cave. Nothing goes back to `start`.
+    ways: Vec<Vec<usize>>,
+    /// Paths to `end` from a cave, after visiting a set of small caves,
+    /// whether a small cave was visited twice.
+    counted: HashMap<(usize, u64, bool), usize>,
+}
+
+impl Paths {
+    /// Fails when two big caves are connected, as paths could go back and
+    /// forth between them forever.
+    fn new(caves: &Caves, part: u8) -> Result<Paths, SolveError> {
+        let error = |reason: String| SolveError::new(Day12::DAY, part, reason);
+
+        let mut small = caves
+            .keys()
+            .copied()
+            .filter(|&c| is_small_cave(c) && c != "start" && c != "end")
+            .collect::<Vec<_>>();
+        small.sort_unstable();
+        small.extend(["start", "end"]);
+        let index = |cave: &str| small.iter().position(|&c| c == cave);
+
+        let mut ways = vec![vec![0; small.len()]; small.len()];
+        for (from, cave) in small.iter().enumerate() {
+            for &next in caves.get(cave).into_iter().flatten() {
+                let reached = match is_small_cave(next) {
+                    true => vec![next],
+                    false => caves.get(next).cloned().unwrap_or_default(),
+                };
+                for to in reached.into_iter().filter(|&c| c != "start") {
+                    let to = index(to).ok_or_else(|| {
+                        error(format!("big caves {} and {} are connected", next, to))
+                    })?;
+                    ways[from][to] += 1;
+                }
+            }
+        }
+
+        Ok(Paths {
+            part,
+            ways,
+            counted: HashMap::new(),
+        })
+    }
+
+    fn count(mut self) -> Result<usize, SolveError> {
+        let start = self.ways.len() - 2;
+        let count = if self.ways.len() <= MAX_SMALL_CAVES + 2 {
+            self.count_from(start, 0, self.part == 1)
+        } else {
+            self.walk_from(start, &mut vec![false; self.ways.len()], self.part == 1)
+        };
+        count.ok_or_else(|| SolveError::new(Day12::DAY, self.part, "number of paths overflows"))
+    }
+
+    /// Paths to `end` from `cave`, or None when there are too many.
+    fn count_from(&mut self, cave: usize, visited: u64, visited_twice: bool) -> Option<usize> {
+        if let Some(&count) = self.counted.get(&(cave, visited, visited_twice)) {
+            return Some(count);
+        }
+
+        let end = self.ways.len() - 1;
+        let mut count = 0usize;
+        for next in 0..self.ways.len() {
+            let ways = self.ways[cave][next];
+            if ways == 0 {
+                continue;
+            }
+
+            let paths = if next == end {
+                1
+            } else if visited & 1 << next == 0 {
+                self.count_from(next, visited | 1 << next, visited_twice)?
+            } else if !visited_twice {
+                self.count_from(next, visited, true)?
+            } else {
+                0
+            };
+            count = count.checked_add(ways.checked_mul(paths)?)?;
+        }
+
+        self.counted.insert((cave, visited, visited_twice), count);
+        Some(count)
+    }
+
+    /// Same as [`Paths::count_from`], walking every path for sets of small
+    /// caves too large to remember.
+    fn walk_from(&self, cave: usize, visited: &mut [bool], visited_twice: bool) -> Option<usize> {
+        let end = self.ways.len() - 1;
+        let mut count = 0usize;
+        for next in 0..self.ways.len() {
+            let ways = self.ways[cave][next];
+            if ways == 0 {
+                continue;
+            }
+
+            let paths = if next == end {
+                1
+            } else if !visited[next] {
+                visited[next] = true;
+                let paths = self.walk_from(next, visited, visited_twice);
+                visited[next] = false;
+                paths?
+            } else if !visited_twice {
+                self.walk_from(next, visited, true)?
+            } else {
+                0
+            };
+            count = count.checked_add(ways.checked_mul(paths)?)?;
+        }
+
+        Some(count)
+    }
+}
+
+/// Caves connected to each cave, by name. Small caves have lowercase names.
+pub type Caves<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+/// Reads one `a-b` connection between two caves per line.
+pub fn parse(input: &str) -> Result<Caves<'_>, ParseError> {
+    input.lines().map(|l| parse_connexion(input, l)).try_fold(
+        HashMap::new(),
+        |mut acc, connexion| {
+            let (c1, c2) = connexion?;
+            // A connection listed twice is still a single tunnel.
+            let neighbors: &mut Vec<_> = acc.entry(c1).or_default();
+            if !neighbors.contains(&c2) {
+                neighbors.push(c2);
+                acc.entry(c2).or_default().push(c1);
+            }
+            Ok(acc)
+        },
+    )
+}
+
+fn parse_connexion<'a>(input: &str, l: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    let error = |token: &str, expected: &str| ParseError::at(Day12::DAY, input, token, expected);
+
+    let (c1, c2) = l
+        .split_once('-')
+        .ok_or_else(|| error(l, "cave connexion"))?;
+    for c in [c1, c2] {
+        if c.is_empty() || !c.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(error(c, "cave name"));
+        }
+    }
+    // Paths could go back and forth between two big caves forever.
+    if !is_small_cave(c1) && !is_small_cave(c2) {
+        return Err(error(l, "connection to a small cave"));
+    }
+
+    Ok((c1, c2))
+}
+
+/// Every line [`parse`] would stop at, and a missing start or end cave,
+/// without which there is no path.
+pub fn check(input: &str) -> Vec<ParseError> {
+    let mut errors = Vec::new();
+    let mut caves = HashSet::new();
+
+    for l in input.lines() {
+        match parse_connexion(input, l) {
+            Ok((c1, c2)) => caves.extend([c1, c2]),
+            Err(e) => errors.push(e),
+        }
+    }
+
+    for cave in ["start", "end"] {
+        if !caves.contains(cave) {
+            errors.push(ParseError::at(
+                Day12::DAY,
+                input,
+                &input[input.len()..],
+                format!("connection to the {} cave", cave),
+            ));
+        }
+    }
+
+    errors
+}
+
+fn is_small_cave(s: &str) -> bool {
+    s.chars().next().is_some_and(char::is_lowercase)
+}
+
+#[cfg(test)]
+mod test {
+    use common::{Generator, Rng};
+
+    use super::*;
+
+    #[test]
+    fn part1_small_test() {
+        let caves = parse(
+            "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end
+",
+        )
+        .unwrap();
+        assert_eq!(part1(&caves), Ok(10));
+    }
+
+    #[test]
+    fn part1_medium_test() {
+        let caves = parse(
+            "dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc
+",
+        )
+        .unwrap();
+        assert_eq!(part1(&caves), Ok(19));
+    }
+
+    #[test]
+    fn part1_large_test() {
+        let caves = parse(Day12::METADATA.example).unwrap();
+        assert_eq!(part1(&caves), Ok(226));
+    }
+
+    #[test]
+    fn part2_test() {
+        let caves = parse(Day12::METADATA.example).unwrap();
+        assert_eq!(part2(&caves), Ok(3509));
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = parse("start-A\nstart-b\nA-c\nA b\n").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.expected, "cave connexion");
+
+        let error = parse("start-A\nstart-b\nA-\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.text, "");
+
+        let error = parse("start-A\nA-B\nB-end\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "connection to a small cave");
+    }
+
+    #[test]
+    fn duplicate_connection_test() {
+        let caves = parse("start-A\nA-end\nend-A\nstart-A\n").unwrap();
+        assert_eq!((part1(&caves), part2(&caves)), (Ok(1), Ok(1)));
+    }
+
+    #[test]
+    fn no_start_test() {
+        let caves = parse("a-end\n").unwrap();
+        assert_eq!((part1(&caves), part2(&caves)), (Ok(0), Ok(0)));
+    }
+
+    /// Every connection between `small` small caves, start and end.
+    fn complete(small: usize) -> String {
+        let caves = (0..small)
+            .map(|i| format!("c{}", char::from(b'a' + i as u8)))
+            .chain(["start".to_string(), "end".to_string()])
+            .collect::<Vec<_>>();
+
+        let mut input = String::new();
+        for (i, c1) in caves.iter().enumerate() {
+            for c2 in &caves[i + 1..] {
+                input.push_str(&format!("{}-{}\n", c1, c2));
+            }
+        }
+        input
+    }
+
+    #[test]
+    fn complete_test() {
+        let input = complete(9);
+        let caves = parse(&input).unwrap();
+        assert_eq!((part1(&caves), part2(&caves)), (Ok(986410), Ok(29099098)));
+
+        // Walking every path counts the same as remembering them.
+        let input = complete(6);
+        let caves = parse(&input).unwrap();
+        for part in [1, 2] {
+            let mut paths = Paths::new(&caves, part).unwrap();
+            let start = paths.ways.len() - 2;
+            let walked = paths.walk_from(start, &mut [false; 8], part == 1);
+            assert_eq!(walked, paths.count_from(start, 0, part == 1));
+        }
+    }
+
+    /// `start`, then a chain of `small` small caves, then `end`.
+    fn chain(small: usize) -> String {
+        let caves = std::iter::once("start".to_string())
+            .chain((0..small).map(|i| {
+                let letter = |n: usize| char::from(b'a' + n as u8);
+                format!("c{}{}", letter(i / 26), letter(i % 26))
+            }))
+            .chain(["end".to_string()])
+            .collect::<Vec<_>>();
+        caves
+            .windows(2)
+            .map(|w| format!("{}-{}\n", w[0], w[1]))
+            .collect()
+    }
+
+    #[test]
+    fn many_small_caves_test() {
+        for small in [20, MAX_SMALL_CAVES, MAX_SMALL_CAVES + 1, 100] {
+            let input = chain(small);
+            let caves = parse(&input).unwrap();
+            assert_eq!((part1(&caves), part2(&caves)), (Ok(1), Ok(1)), "{}", small);
+        }
+    }
+
+    #[test]
+    fn unparsed_caves_test() {
+        let caves = Caves::from([
+            ("start", vec!["A"]),
+            ("A", vec!["start", "B"]),
+            ("B", vec!["A", "end"]),
+            ("end", vec!["B"]),
+        ]);
+        assert_eq!(
+            part1(&caves).unwrap_err().reason,
+            "big caves A and B are connected"
+        );
+
+        let names = (0..100).map(|i| format!("c{}", i)).collect::<Vec<_>>();
+        let caves = names
+            .iter()
+            .map(|c| (c.as_str(), vec!["start"]))
+            .collect::<Caves>();
+        assert_eq!((part1(&caves), part2(&caves)), (Ok(0), Ok(0)));
+    }
+
+    #[test]
+    fn overflow_test() {
+        // Each big cave is one more way to go between any two small caves.
+        let mut input = complete(16);
+        for big in ["BA", "BB", "BC", "BD", "BE", "BF", "BG", "BH"] {
+            for i in 0..16 {
+                input.push_str(&format!("{}-c{}\n", big, char::from(b'a' + i as u8)));
+            }
+        }
+
+        let caves = parse(&input).unwrap();
+        assert_eq!(
+            part1(&caves).unwrap_err().to_string(),
+            "Day 12, part 1: number of paths overflows"
+        );
+    }
+
+    #[test]
+    fn generate_test() {
+        for seed in 0..10 {
+            let input = Day12::generate(&mut Rng::new(seed), 6);
+            let input = Day12::parse(&input).unwrap();
+            Day12::part1(&input).unwrap();
+            Day12::part2(&input).unwrap();
+        }
+    }
+
+    #[test]
+    fn check_test() {
+        let errors = check("start-A\nA-B\nA-c\nA b\n");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column, e.expected.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (2, 1, "connection to a small cave"),
+                (4, 1, "cave connexion"),
+                (5, 1, "connection to the end cave"),
+            ]
+        );
+    }
+}
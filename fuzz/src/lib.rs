@@ -0,0 +1,23 @@
+//! Shared body of the fuzz targets, one per day: any input that parses must
+//! be solved, or rejected with an error, without panicking or hanging.
+//!
+//! ```text
+//! cargo +nightly fuzz run day05 -- -max_total_time=60
+//! cargo +nightly fuzz cmin day05
+//! ```
+//!
+//! The corpus of every target is checked in under `fuzz/corpus`; minimize it
+//! with `cmin` before committing what a run found.
+
+use common::Solution;
+
+pub fn solve<S: Solution>(data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(input) = S::parse(input) {
+        let _ = S::part1(&input);
+        let _ = S::part2(&input);
+    }
+}
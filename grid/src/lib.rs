@@ -0,0 +1,340 @@
+//! Two dimensional grid of values, as found in the puzzles drawn as maps.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use common::ParseError;
+
+/// Offsets of the 4 neighbors sharing a side with a cell.
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the 8 neighbors sharing a side or a corner with a cell.
+const ALL: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Rectangle of `width` by `height` values stored row after row, indexed by
+/// column `x` then row `y` from the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid of the values of `data`, row after row. Panics unless `data` holds
+    /// exactly `width * height` values.
+    pub fn new(width: usize, height: usize, data: Vec<T>) -> Grid<T> {
+        assert_eq!(data.len(), width * height, "Grid data does not fit");
+        Grid {
+            data,
+            width,
+            height,
+        }
+    }
+
+    /// Reads one row per line, one value per character converted by `cell`.
+    /// `expected` names a value in errors, e.g. `digit`. Rows must not be
+    /// empty and must all have the same length.
+    pub fn parse(
+        day: u8,
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let error = |token: &str, expected: &str| ParseError::at(day, input, token, expected);
+
+        let width = input
+            .lines()
+            .next()
+            .filter(|l| !l.is_empty())
+            .ok_or_else(|| error(&input[..0], &format!("row of {}s", expected)))?
+            .chars()
+            .count();
+
+        let mut data = Vec::with_capacity(width * input.len() / (width + 1));
+        let mut height = 0;
+
+        for l in input.lines() {
+            if l.chars().count() != width {
+                return Err(error(l, &format!("row of {} {}s", width, expected)));
+            }
+            for (i, c) in l.char_indices() {
+                data.push(cell(c).ok_or_else(|| error(&l[i..i + c.len_utf8()], expected))?);
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width, height, data))
+    }
+
+    /// Every problem [`Grid::parse`] would stop at: rows of another length
+    /// than the first one and characters `cell` does not convert.
+    pub fn check(
+        day: u8,
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Vec<ParseError> {
+        let error = |token: &str, expected: &str| ParseError::at(day, input, token, expected);
+
+        let width = match input.lines().next().filter(|l| !l.is_empty()) {
+            Some(l) => l.chars().count(),
+            None => return vec![error(&input[..0], &format!("row of {}s", expected))],
+        };
+
+        let mut errors = Vec::new();
+        for l in input.lines() {
+            if l.chars().count() != width {
+                errors.push(error(l, &format!("row of {} {}s", width, expected)));
+            }
+            for (i, c) in l.char_indices() {
+                if cell(c).is_none() {
+                    errors.push(error(&l[i..i + c.len_utf8()], expected));
+                }
+            }
+        }
+        errors
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of values, `width * height`.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_of(x, y).map(|i| &self.data[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(|i| &mut self.data[i])
+    }
+
+    /// Coordinates of the up to 4 cells sharing a side with `(x, y)`. The
+    /// iterator does not borrow the grid, which can be updated meanwhile.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(x, y, &ORTHOGONAL)
+    }
+
+    /// Coordinates of the up to 8 cells sharing a side or a corner with
+    /// `(x, y)`. The iterator does not borrow the grid either.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(x, y, &ALL)
+    }
+
+    /// Coordinates of every cell, row after row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Values row after row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a width of 0, which only an empty grid can have.
+        self.data.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} out of the grid", x);
+        self.data[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+
+    fn offsets(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let x = x.checked_add_signed(dx)?;
+            let y = y.checked_add_signed(dy)?;
+            (x < width && y < height).then_some((x, y))
+        })
+    }
+}
+
+impl Grid<u8> {
+    /// Reads one row of decimal digits per line.
+    pub fn parse_digits(day: u8, input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse(day, input, "digit", digit)
+    }
+
+    /// Every problem [`Grid::parse_digits`] would stop at.
+    pub fn check_digits(day: u8, input: &str) -> Vec<ParseError> {
+        Grid::check(day, input, "digit", digit)
+    }
+}
+
+fn digit(c: char) -> Option<u8> {
+    c.to_digit(10).map(|d| d as u8)
+}
+
+impl Grid<char> {
+    /// Reads one row of characters per line, e.g. a map of `#` and `.`.
+    pub fn parse_chars(day: u8, input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(day, input, "character", Some)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) out of the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) out of the grid", x, y))
+    }
+}
+
+/// Writes one line per row, values side by side.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::parse_digits(1, "123\n456\n").unwrap()
+    }
+
+    #[test]
+    fn parse_test() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let map = Grid::parse_chars(1, "#.\n.#").unwrap();
+        assert_eq!(map.iter().collect::<String>(), "#..#");
+    }
+
+    #[test]
+    fn parse_error_test() {
+        let error = Grid::parse_digits(9, "123\n4a6").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (9, 2, 2));
+        assert_eq!(error.expected, "digit");
+
+        let error = Grid::parse_digits(9, "123\n45").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "row of 3 digits");
+
+        let error = Grid::parse_digits(9, "").unwrap_err();
+        assert_eq!(error.expected, "row of digits");
+    }
+
+    #[test]
+    fn get_test() {
+        let mut grid = grid();
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+
+        *grid.get_mut(1, 1).unwrap() = 0;
+        grid[(0, 0)] += 1;
+        assert_eq!(grid.to_string(), "223\n406\n");
+    }
+
+    #[test]
+    fn neighbors_test() {
+        let grid = grid();
+        assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors4(1, 1).count(), 3);
+        assert_eq!(
+            grid.neighbors8(1, 0).collect::<Vec<_>>(),
+            [(0, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn rows_and_columns_test() {
+        let grid = grid();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), [&2, &5]);
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.copied().sum::<u8>())
+                .collect::<Vec<_>>(),
+            [5, 7, 9]
+        );
+        assert_eq!(grid.positions().nth(4), Some((1, 1)));
+    }
+
+    #[test]
+    fn check_test() {
+        assert_eq!(Grid::check_digits(9, "123\n456\n"), []);
+
+        let errors = Grid::check_digits(9, "123\n4a6\n78\n9b9c");
+        assert_eq!(
+            errors
+                .iter()
+                .map(|e| (e.line, e.column, e.expected.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (2, 2, "digit"),
+                (3, 1, "row of 3 digits"),
+                (4, 1, "row of 3 digits"),
+                (4, 2, "digit"),
+                (4, 4, "digit"),
+            ]
+        );
+    }
+}